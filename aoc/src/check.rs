@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+use crate::days::{self, Answer, Day};
+use crate::{read_input, wanted_parts, Options};
+
+// answers by day and part
+type Answers = BTreeMap<(u32, usize), String>;
+
+// The answers file has a line `day part answer` per answer. Pictures (days 8
+// and 11) span several lines, so newlines are written as `\n`.
+fn escape (answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape (answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some(char) => unescaped.push(char),
+                None => {}
+            },
+            char => unescaped.push(char)
+        }
+    }
+    unescaped
+}
+
+fn parse_line (line: &str) -> Result<((u32, usize), String), String> {
+    let fields = line.splitn(3, ' ').collect::<Vec<&str>>();
+    let number = |field: &str| field.parse::<usize>().map_err(|_| format!("invalid answer line: {}", line));
+    if fields.len() != 3 {
+        return Err(format!("invalid answer line: {}", line))
+    }
+    Ok(((number(fields[0])? as u32, number(fields[1])?), unescape(fields[2])))
+}
+
+// a file that doesn't exist yet has no answers
+fn load (path: &str) -> Result<Answers, String> {
+    match fs::read_to_string(path) {
+        Ok(file) => file.lines().filter(|line| !line.is_empty()).map(parse_line).collect(),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::new()),
+        Err(error) => Err(format!("unable to read {}: {}", path, error))
+    }
+}
+
+fn save (path: &str, answers: &Answers) -> Result<(), String> {
+    let lines = answers.iter().map(|((day, part), answer)| format!("{} {} {}\n", day, part, escape(answer))).collect::<String>();
+    fs::write(path, lines).map_err(|error| format!("unable to write {}: {}", path, error))
+}
+
+// Runs every part and reports it as passed, failed (a different answer, or
+// the day panicked) or missing (no input, or no recorded answer). Recording
+// keeps the answers of days that weren't run. Returns false if any failed.
+pub fn check (options: &Options, days: &[Day]) -> bool {
+    let path = options.answers.clone().unwrap_or_else(|| format!("{}/answers.txt", options.inputs.as_deref().unwrap_or(".")));
+    let mut answers = match load(&path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        let parts = match wanted_parts(options, day) {
+            Ok(parts) => parts,
+            Err(error) => {
+                println!("{}", error);
+                failed += 1;
+                continue
+            }
+        };
+        if parts.is_empty() {
+            continue
+        }
+        let input = match read_input(options, day) {
+            Ok(input) => input,
+            Err(error) => {
+                for part in parts {
+                    println!("day {} part {}: missing, {}", day.number, part, error);
+                    missing += 1;
+                }
+                continue
+            }
+        };
+
+        for Answer { part, answer, elapsed } in days::run(day, &input, &parts) {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    println!("day {} part {}: failed, the solution panicked", day.number, part);
+                    failed += 1;
+                    continue
+                }
+            };
+
+            if options.record {
+                println!("day {} part {}: recorded {} ({:?})", day.number, part, escape(&answer), elapsed);
+                answers.insert((day.number, part), answer);
+                passed += 1;
+                continue
+            }
+
+            match answers.get(&(day.number, part)) {
+                Some(expected) if *expected == answer => {
+                    println!("day {} part {}: passed ({:?})", day.number, part, elapsed);
+                    passed += 1;
+                },
+                Some(expected) => {
+                    println!("day {} part {}: failed, expected {} but got {}", day.number, part, escape(expected), escape(&answer));
+                    failed += 1;
+                },
+                None => {
+                    println!("day {} part {}: missing, no recorded answer (got {})", day.number, part, escape(&answer));
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    if options.record {
+        if let Err(error) = save(&path, &answers) {
+            eprintln!("{}", error);
+            return false
+        }
+        println!("{} recorded, {} failed, {} missing, written to {}", passed, failed, missing, path);
+    } else {
+        println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    }
+    failed == 0
+}
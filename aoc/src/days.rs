@@ -0,0 +1,76 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use solver::Solver;
+
+// Parses the puzzle input, as read from the file, once and passes each part
+// number with a function computing its answer to the callback, which decides
+// which parts run.
+pub type Handler = fn(&str, &mut dyn FnMut(usize, &dyn Fn() -> String));
+
+pub struct Day {
+    pub number: u32,
+    // 1, or 2 if part 2 is solved
+    pub parts: usize,
+    pub solve: Handler
+}
+
+fn solve<S: Solver> (input: &str, part: &mut dyn FnMut(usize, &dyn Fn() -> String)) {
+    let parsed = S::parse(input);
+    part(1, &|| S::part1(&parsed).to_string());
+    part(2, &|| S::part2(&parsed).to_string());
+}
+
+pub struct Answer {
+    pub part: usize,
+    // `None` if the solution panicked
+    pub answer: Option<String>,
+    // without parsing the input
+    pub elapsed: Duration
+}
+
+// Runs `parts` of the day, in order. A part that panics doesn't stop the
+// others, but if parsing panics none of them has an answer.
+pub fn run (day: &Day, input: &str, parts: &[usize]) -> Vec<Answer> {
+    let mut answers = Vec::new();
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(input, &mut |part, solve| {
+        if parts.contains(&part) {
+            let start = Instant::now();
+            let answer = panic::catch_unwind(AssertUnwindSafe(solve)).ok();
+            answers.push(Answer { part, answer, elapsed: start.elapsed() });
+        }
+    })));
+
+    for &part in parts.iter().skip(answers.len()) {
+        answers.push(Answer { part, answer: None, elapsed: Duration::default() });
+    }
+    answers
+}
+
+pub fn all () -> Vec<Day> {
+    vec![
+        Day { number: 1, parts: 2, solve: solve::<day_01::Solution> },
+        Day { number: 2, parts: 2, solve: solve::<day_02::Solution> },
+        Day { number: 3, parts: 2, solve: solve::<day_03::Solution> },
+        Day { number: 4, parts: 2, solve: solve::<day_04::Solution> },
+        Day { number: 5, parts: 2, solve: solve::<day_05::Solution> },
+        Day { number: 6, parts: 2, solve: solve::<day_06::Solution> },
+        Day { number: 7, parts: 2, solve: solve::<day_07::Solution> },
+        Day { number: 8, parts: 2, solve: solve::<day_08::Solution> },
+        Day { number: 9, parts: 2, solve: solve::<day_09::Solution> },
+        Day { number: 10, parts: 2, solve: solve::<day_10::Solution> },
+        Day { number: 11, parts: 2, solve: solve::<day_11::Solution> },
+        Day { number: 12, parts: 2, solve: solve::<day_12::Solution> },
+        Day { number: 13, parts: 2, solve: solve::<day_13::Solution> },
+        Day { number: 14, parts: 2, solve: solve::<day_14::Solution> },
+        Day { number: 15, parts: 2, solve: solve::<day_15::Solution> },
+        Day { number: 16, parts: 1, solve: solve::<day_16::Solution> },
+        Day { number: 17, parts: 2, solve: solve::<day_17::Solution> },
+        Day { number: 18, parts: 1, solve: solve::<day_18::Solution> },
+        Day { number: 19, parts: 2, solve: solve::<day_19::Solution> },
+        Day { number: 20, parts: 2, solve: solve::<day_20::Solution> },
+        Day { number: 21, parts: 2, solve: solve::<day_21::Solution> },
+        Day { number: 23, parts: 2, solve: solve::<day_23::Solution> },
+        Day { number: 24, parts: 1, solve: solve::<day_24::Solution> }
+    ]
+}
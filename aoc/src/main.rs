@@ -0,0 +1,157 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+mod check;
+mod days;
+
+use days::{Answer, Day};
+
+static USAGE: &str = "usage: aoc run [--day <n>] [--part <1|2>] [--input <file>|-] [--inputs <dir>]
+       aoc check [--day <n>] [--part <1|2>] [--inputs <dir>] [--answers <file>] [--record]
+
+`run` runs one day, or every day if --day is left out. The input is read from
+the --input file, from stdin with `--input -`, or else from
+<dir>/day-NN/input.txt (the current directory by default).
+
+`check` compares the answers with the answers file (<dir>/answers.txt by
+default) and fails if any differ. With --record it writes the answers instead.";
+
+#[derive(Default)]
+struct Options {
+    day: Option<u32>,
+    part: Option<usize>,
+    input: Option<String>,
+    inputs: Option<String>,
+    answers: Option<String>,
+    record: bool
+}
+
+fn parse_options (command: &str, arguments: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut arguments = arguments.iter();
+
+    while let Some(flag) = arguments.next() {
+        let mut value = || arguments.next().ok_or(format!("missing value for {}", flag));
+        match flag.as_str() {
+            "--day" => options.day = Some(value()?.parse().map_err(|_| String::from("invalid day"))?),
+            "--part" => match value()?.as_str() {
+                "1" => options.part = Some(1),
+                "2" => options.part = Some(2),
+                _ => return Err(String::from("part must be 1 or 2"))
+            },
+            "--input" => options.input = Some(value()?.to_string()),
+            "--inputs" => options.inputs = Some(value()?.to_string()),
+            "--answers" if command == "check" => options.answers = Some(value()?.to_string()),
+            "--record" if command == "check" => options.record = true,
+            _ => return Err(format!("unknown option {}", flag))
+        }
+    }
+
+    if options.input.is_some() && command == "check" {
+        return Err(String::from("check reads its inputs from --inputs"))
+    }
+
+    if options.input.is_some() && options.day.is_none() {
+        return Err(String::from("--input needs --day"))
+    }
+    Ok(options)
+}
+
+fn read_input (options: &Options, day: &Day) -> Result<String, String> {
+    let path = match &options.input {
+        Some(path) if path == "-" => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map_err(|error| format!("unable to read stdin: {}", error))?;
+            return Ok(input)
+        },
+        Some(path) => path.to_string(),
+        None => format!("{}/day-{:02}/input.txt", options.inputs.as_deref().unwrap_or("."), day.number)
+    };
+    fs::read_to_string(&path).map_err(|error| format!("unable to read {}: {}", path, error))
+}
+
+// The parts of `day` to run. With `--part`, a day without that part is
+// skipped, unless it was asked for with `--day`.
+fn wanted_parts (options: &Options, day: &Day) -> Result<Vec<usize>, String> {
+    match options.part {
+        Some(part) if part > day.parts => match options.day {
+            Some(_) => Err(format!("day {} has no part {}", day.number, part)),
+            None => Ok(Vec::new())
+        },
+        Some(part) => Ok(vec![part]),
+        None => Ok((1..=day.parts).collect())
+    }
+}
+
+fn run_day (options: &Options, day: &Day) -> Result<(), String> {
+    let parts = wanted_parts(options, day)?;
+    if parts.is_empty() {
+        return Ok(())
+    }
+    let input = read_input(options, day)?;
+
+    let mut panicked = Vec::new();
+    for Answer { part, answer, elapsed } in days::run(day, &input, &parts) {
+        let answer = match answer {
+            Some(answer) => answer,
+            None => {
+                panicked.push(format!("day {} part {}: the solution panicked", day.number, part));
+                continue
+            }
+        };
+
+        // pictures (days 8 and 11) go below the heading
+        if answer.contains('\n') {
+            println!("day {} part {} ({:?}):\n{}", day.number, part, elapsed, answer);
+        } else {
+            println!("day {} part {}: {} ({:?})", day.number, part, answer, elapsed);
+        }
+    }
+
+    if panicked.is_empty() {
+        Ok(())
+    } else {
+        Err(panicked.join("\n"))
+    }
+}
+
+// with every day, a missing input skips that day but still fails the run
+fn run (options: &Options, days: &[Day]) -> bool {
+    let mut passed = true;
+    for day in days {
+        if let Err(error) = run_day(options, day) {
+            eprintln!("{}", error);
+            passed = false;
+        }
+    }
+    passed
+}
+
+fn main () {
+    let arguments = env::args().collect::<Vec<String>>();
+    let command = arguments.get(1).map_or("", |command| command.as_str());
+    if command != "run" && command != "check" {
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    }
+    let options = parse_options(command, &arguments[2..]).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(1);
+    });
+
+    let days = days::all().into_iter().filter(|day| options.day.is_none_or(|wanted| wanted == day.number)).collect::<Vec<Day>>();
+    if days.is_empty() {
+        eprintln!("day {} is not solved", options.day.unwrap_or(0));
+        process::exit(1);
+    }
+
+    let passed = match command {
+        "check" => check::check(&options, &days),
+        _ => run(&options, &days)
+    };
+    if !passed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,73 @@
+use std::cmp;
+
+use solver::Solver;
+
+pub fn calculate_required_fuel (mass: i32) -> i32 {
+	(mass / 3) - 2
+}
+
+pub fn calculate_required_fuel_recursively (mass: i32) -> i32 {
+	let mut fuel_mass = 0;
+	let mut added_mass = mass;
+	while added_mass > 0 {
+		added_mass = cmp::max(calculate_required_fuel(added_mass), 0);
+		fuel_mass += added_mass
+	}
+
+	fuel_mass
+}
+
+fn parse_number (string: &str) -> i32 {
+	string.parse::<i32>().expect("cannot parse string as int")
+}
+
+pub fn calculate_module_fuel (modules: &[i32], predicate: &dyn Fn(i32) -> i32) -> i32 {
+	modules.iter().map(|&x| predicate(x)).sum()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+	type Input<'a> = Vec<i32>;
+	type Answer1 = i32;
+	type Answer2 = i32;
+
+	fn parse (input: &str) -> Vec<i32> {
+		input.trim().split('\n').map(parse_number).collect()
+	}
+
+	fn part1 (modules: &Vec<i32>) -> i32 {
+		calculate_module_fuel(modules, &calculate_required_fuel)
+	}
+
+	fn part2 (modules: &Vec<i32>) -> i32 {
+		calculate_module_fuel(modules, &calculate_required_fuel_recursively)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fuel_for_module_mass () {
+		assert_eq!(calculate_required_fuel(12), 2);
+		assert_eq!(calculate_required_fuel(14), 2);
+		assert_eq!(calculate_required_fuel(1969), 654);
+		assert_eq!(calculate_required_fuel(100756), 33583);
+	}
+
+	#[test]
+	fn fuel_including_fuel_mass () {
+		assert_eq!(calculate_required_fuel_recursively(14), 2);
+		assert_eq!(calculate_required_fuel_recursively(1969), 966);
+		assert_eq!(calculate_required_fuel_recursively(100756), 50346);
+	}
+
+	#[test]
+	fn total_fuel () {
+		let modules = Solution::parse("12\n14\n1969\n100756\n");
+		assert_eq!(Solution::part1(&modules), 2 + 2 + 654 + 33583);
+		assert_eq!(Solution::part2(&modules), 2 + 2 + 966 + 50346);
+	}
+}
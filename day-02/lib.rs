@@ -0,0 +1,59 @@
+use solver::Solver;
+
+pub fn run_intcode (intcode: &[i64], noun: i64, verb: i64) -> i64 {
+    let mut state = intcode::ProgramState::new(intcode);
+    state.tape[1] = noun;
+    state.tape[2] = verb;
+
+    while intcode::step(&mut state).expect("intcode program failed").is_some() {}
+
+    state.tape[0]
+}
+
+pub fn simulate_noun_verb (intcode: &[i64], target: i64) -> Option<i64> {
+    for noun in 0..99 {
+        for verb in 0..99 {
+            if run_intcode(intcode, noun, verb) == target {
+                return Some(100 * noun + verb)
+            }
+        }
+    }
+    None
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (tape: &Vec<i64>) -> i64 {
+        run_intcode(tape, 12, 2)
+    }
+
+    fn part2 (tape: &Vec<i64>) -> i64 {
+        simulate_noun_verb(tape, 19690720).expect("no solution found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_example_programs () {
+        // the noun and verb are the example's own values at addresses 1 and 2
+        assert_eq!(run_intcode(&Solution::parse("1,9,10,3,2,3,11,0,99,30,40,50"), 9, 10), 3500);
+        assert_eq!(run_intcode(&[1, 0, 0, 0, 99], 0, 0), 2);
+        assert_eq!(run_intcode(&[1, 1, 1, 4, 99, 5, 6, 0, 99], 1, 1), 30);
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use solver::Solver;
+
+// Thanks to Egon Willighagen (@egonw) for thinking of implementing this as sets of points
+
+fn make_set_from_path (wire: &[(i32, i32)]) -> HashSet<&(i32, i32)> {
+    let mut set = HashSet::new();
+    for point in wire {
+        set.insert(point);
+    }
+    set
+}
+
+fn find_intersections (wires: &[Vec<(i32, i32)>]) -> Vec<&(i32, i32)> {
+    let mut intersections = Vec::new();
+    let a = make_set_from_path(&wires[0]);
+    let b = make_set_from_path(&wires[1]);
+
+    for point in a.intersection(&b) {
+        intersections.push(*point)
+    }
+
+    intersections
+}
+
+pub type Comparator = dyn Fn(&(i32, i32), &[Vec<(i32, i32)>]) -> i32;
+
+pub fn get_distance (point: &(i32, i32), _: &[Vec<(i32, i32)>]) -> i32 {
+    point.0.abs() + point.1.abs()
+}
+
+fn get_index_of (vector: &[(i32, i32)], element: &(i32, i32)) -> Option<usize> {
+    vector.iter().position(|point| point == element)
+}
+
+pub fn get_timing (point: &(i32, i32), paths: &[Vec<(i32, i32)>]) -> i32 {
+    paths.iter().map(|path| get_index_of(path, point).unwrap() as i32 + 1).sum()
+}
+
+pub fn find_specific_intersection (wires: &[Vec<(i32, i32)>], comparator: &Comparator) -> i32 {
+    let intersections = find_intersections(wires);
+    let point = intersections.iter().min_by(|&a, &b| comparator(a, wires).cmp(&comparator(b, wires))).unwrap();
+
+    comparator(point, wires)
+}
+
+#[derive(Debug)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+#[derive(Debug)]
+struct PathPart {
+    direction: Direction,
+    distance: i32
+}
+
+impl From<&str> for PathPart {
+    fn from (string: &str) -> Self {
+        let parts = string.split_at(1);
+        let direction = match parts.0 {
+            "U" => Direction::Up,
+            "R" => Direction::Right,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            _ => panic!("unknown direction")
+        };
+        PathPart {
+            direction,
+            distance: parts.1.parse::<i32>().expect("cannot parse string as int")
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Path {
+    parts: Vec<PathPart>,
+    part_index: i32,
+    part_distance: i32,
+    x: i32,
+    y: i32
+}
+
+impl Iterator for Path {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<(i32, i32)> {
+        if self.part_index as usize >= self.parts.len() {
+            return None
+        }
+
+        let part = &self.parts[self.part_index as usize];
+        match part.direction {
+            Direction::Up => self.y -= 1,
+            Direction::Right => self.x += 1,
+            Direction::Down => self.y += 1,
+            Direction::Left => self.x -= 1
+        };
+
+        if self.part_distance == part.distance - 1 {
+            self.part_distance = 0;
+            self.part_index += 1;
+        } else {
+            self.part_distance += 1;
+        }
+
+        Some((self.x, self.y))
+    }
+}
+
+impl From<&str> for Path {
+    fn from (string: &str) -> Self {
+        let parts = string.split(',').map(PathPart::from).collect();
+        Path {
+            parts,
+            part_index: 0,
+            part_distance: 0,
+            x: 0,
+            y: 0
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Vec<(i32, i32)>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse (input: &str) -> Vec<Vec<(i32, i32)>> {
+        input.trim().split('\n').map(|x| Path::from(x).collect()).collect()
+    }
+
+    fn part1 (wires: &Vec<Vec<(i32, i32)>>) -> i32 {
+        find_specific_intersection(wires, &get_distance)
+    }
+
+    fn part2 (wires: &Vec<Vec<(i32, i32)>>) -> i32 {
+        find_specific_intersection(wires, &get_timing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLES: [(&str, i32, i32); 3] = [
+        ("R8,U5,L5,D3\nU7,R6,D4,L4", 6, 30),
+        ("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83", 159, 610),
+        ("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7", 135, 410)
+    ];
+
+    #[test]
+    fn closest_intersection () {
+        for (wires, distance, _) in &EXAMPLES {
+            assert_eq!(find_specific_intersection(&Solution::parse(wires), &get_distance), *distance);
+        }
+    }
+
+    #[test]
+    fn fewest_combined_steps () {
+        for (wires, _, steps) in &EXAMPLES {
+            assert_eq!(find_specific_intersection(&Solution::parse(wires), &get_timing), *steps);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use solver::Solver;
+
+pub fn check_password (password: i32) -> bool {
+    let mut increasing = true;
+    let mut consecutive_digits = false;
+
+    let mut previous_digit = 0;
+    for i in (0..6).rev() {
+        let digit = (password % 10_i32.pow(i + 1)) / 10_i32.pow(i);
+
+        if digit < previous_digit {
+            increasing = false
+        }
+
+        if digit == previous_digit {
+            consecutive_digits = true
+        }
+
+        previous_digit = digit
+    }
+
+    increasing && consecutive_digits
+}
+
+pub fn check_password_2 (password: i32) -> bool {
+    let mut increasing = true;
+    let mut two_consecutive_digits = false;
+    let mut consecutive_digits = 1;
+
+    let mut previous_digit = 0;
+    for i in (0..6).rev() {
+        let digit = (password % 10_i32.pow(i + 1)) / 10_i32.pow(i);
+
+        if digit < previous_digit {
+            increasing = false
+        }
+
+        if digit == previous_digit {
+            consecutive_digits += 1
+        } else if consecutive_digits == 2 {
+            two_consecutive_digits = true
+        } else {
+            consecutive_digits = 1
+        }
+
+        previous_digit = digit
+    }
+
+    increasing && (two_consecutive_digits || consecutive_digits == 2)
+}
+
+pub fn find_passwords (lower: i32, upper: i32, check_password: &dyn Fn(i32) -> bool) -> Vec<i32> {
+    let mut passwords = Vec::new();
+    for password in lower..upper {
+        if check_password(password) {
+            passwords.push(password)
+        }
+    }
+
+    passwords
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = (i32, i32);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // the puzzle input is a range like `138307-654504`
+    fn parse (input: &str) -> (i32, i32) {
+        let bounds = input.trim().split('-').map(|bound| bound.parse::<i32>().expect("cannot parse string as int")).collect::<Vec<i32>>();
+        (bounds[0], bounds[1])
+    }
+
+    fn part1 ((lower, upper): &(i32, i32)) -> usize {
+        find_passwords(*lower, *upper, &check_password).len()
+    }
+
+    fn part2 ((lower, upper): &(i32, i32)) -> usize {
+        find_passwords(*lower, *upper, &check_password_2).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacent_and_increasing_digits () {
+        assert!(check_password(111111));
+        assert!(!check_password(223450));
+        assert!(!check_password(123789));
+    }
+
+    #[test]
+    fn pair_not_part_of_larger_group () {
+        assert!(check_password_2(112233));
+        assert!(!check_password_2(123444));
+        assert!(check_password_2(111122));
+    }
+}
@@ -0,0 +1,52 @@
+use solver::Solver;
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+	type Input<'a> = Vec<i64>;
+	type Answer1 = i64;
+	type Answer2 = i64;
+
+	fn parse (input: &str) -> Vec<i64> {
+		input.trim().split(',').map(parse_number).collect()
+	}
+
+	fn part1 (tape: &Vec<i64>) -> i64 {
+		intcode::run(tape, &[1]).expect("intcode program failed").pop().expect("no diagnostic code")
+	}
+
+	fn part2 (tape: &Vec<i64>) -> i64 {
+		intcode::run(tape, &[5]).expect("intcode program failed").pop().expect("no diagnostic code")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn echoes_input () {
+		assert_eq!(intcode::run(&Solution::parse("3,0,4,0,99"), &[42]).unwrap(), vec![42]);
+	}
+
+	#[test]
+	fn compares_with_eight () {
+		// position mode equals, immediate mode less than
+		for (input, equal, less) in [(7, 0, 1), (8, 1, 0), (9, 0, 0)].iter() {
+			assert_eq!(intcode::run(&Solution::parse("3,9,8,9,10,9,4,9,99,-1,8"), &[*input]).unwrap(), vec![*equal]);
+			assert_eq!(intcode::run(&Solution::parse("3,3,1107,-1,8,3,4,3,99"), &[*input]).unwrap(), vec![*less]);
+		}
+	}
+
+	#[test]
+	fn jumps_around_eight () {
+		let tape = Solution::parse("3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99");
+		assert_eq!(intcode::run(&tape, &[7]).unwrap(), vec![999]);
+		assert_eq!(intcode::run(&tape, &[8]).unwrap(), vec![1000]);
+		assert_eq!(intcode::run(&tape, &[9]).unwrap(), vec![1001]);
+	}
+}
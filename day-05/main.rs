@@ -1,18 +1,16 @@
 use std::fs;
-mod intcode;
 
-fn parse_number (string: &str) -> i64 {
-	string.parse::<i64>().expect("cannot parse string as int")
-}
+use solver::Solver;
+use day_05::Solution;
 
 fn main () {
 	let file = fs::read_to_string("./input.txt").expect("unable to download file");
-	let data = file.trim().split(",").map(parse_number).collect();
+	let data = Solution::parse(&file);
 
 	println!(
 		"star 5-1: {:?}
 star 5-2: {:?}",
-		intcode::run(&data, &vec![1]),
-        intcode::run(&data, &vec![5])
+		intcode::run(&data, &[1]).expect("intcode program failed"),
+        intcode::run(&data, &[5]).expect("intcode program failed")
 	);
 }
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::cmp::max;
+
+use solver::Solver;
+
+fn make_chain <'a> (orbits: &'a HashMap<String, String>, satellite: &'a str) -> Vec<&'a str> {
+    let center = orbits.get(satellite).expect("orbit not found");
+    let mut chain = if center == "COM" {
+        Vec::new()
+    } else {
+        make_chain(orbits, center)
+    };
+    chain.push(satellite);
+    chain
+}
+
+pub fn calculate_orbit_checksum (orbits: &HashMap<String, String>) -> usize {
+    let mut sum = 0;
+    for satellite in orbits.keys() {
+        let orbit_chain = make_chain(orbits, satellite);
+        sum += orbit_chain.len()
+    }
+    sum
+}
+
+pub fn calculate_minimal_transfers (orbits: &HashMap<String, String>, a: String, b: String) -> usize {
+    let chain_a = make_chain(orbits, &a);
+    let chain_b = make_chain(orbits, &b);
+    let len_a = chain_a.len();
+    let len_b = chain_b.len();
+    let max_index = max(len_a, len_b) - 1;
+
+    for i in 0..max_index {
+        if chain_a[i] != chain_b[i] {
+            return len_a + len_b - 2 * i - 2;
+        }
+    }
+
+    0
+}
+
+fn parse_orbit (source: &str) -> (String, String) {
+    let orbit: Vec<&str> = source.split(')').collect();
+    (orbit[1].to_string(), orbit[0].to_string())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = HashMap<String, String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse (input: &str) -> HashMap<String, String> {
+        input.trim().split('\n').map(parse_orbit).collect()
+    }
+
+    fn part1 (orbits: &HashMap<String, String>) -> usize {
+        calculate_orbit_checksum(orbits)
+    }
+
+    fn part2 (orbits: &HashMap<String, String>) -> usize {
+        calculate_minimal_transfers(orbits, String::from("YOU"), String::from("SAN"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ORBITS: &str = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L";
+
+    #[test]
+    fn counts_direct_and_indirect_orbits () {
+        assert_eq!(calculate_orbit_checksum(&Solution::parse(ORBITS)), 42);
+    }
+
+    #[test]
+    fn transfers_from_you_to_san () {
+        let orbits = Solution::parse(&format!("{}\nK)YOU\nI)SAN", ORBITS));
+        assert_eq!(calculate_minimal_transfers(&orbits, String::from("YOU"), String::from("SAN")), 4);
+    }
+}
@@ -0,0 +1,116 @@
+use intcode::network::Outcome;
+use solver::Solver;
+
+fn permutate (elements: Vec<i64>) -> Vec<Vec<i64>> {
+    let mut permutations = Vec::new();
+    for element in &elements {
+        let mut other_elements = elements.to_vec();
+        other_elements.retain(|x| x != element);
+        if other_elements.is_empty() {
+            permutations.push(vec![*element])
+        } else {
+            for mut permutation in permutate(other_elements) {
+                permutation.push(*element);
+                permutations.push(permutation.to_vec())
+            }
+        }
+    }
+    permutations
+}
+
+// a chain of amplifiers, each started with its phase setting
+fn calculate_output (code: &[i64], settings: &[i64]) -> i64 {
+    run_amplifiers(code, settings, false)
+}
+
+pub fn find_optimal_settings (code: &[i64]) -> i64 {
+    let possible_phases = (0..5).collect();
+    let possible_settings = permutate(possible_phases);
+    possible_settings.iter().map(|settings| calculate_output(code, settings)).max().unwrap()
+}
+
+// the same amplifiers, with the last one feeding back into the first
+fn calculate_feedback_loop_output (code: &[i64], settings: &[i64]) -> i64 {
+    run_amplifiers(code, settings, true)
+}
+
+fn run_amplifiers (code: &[i64], settings: &[i64], feedback: bool) -> i64 {
+    let mut network = intcode::network::Network::new();
+    let amplifiers = settings.iter().map(|&phase| {
+        let amplifier = network.add(intcode::ProgramState::new(code));
+        network.send(amplifier, phase);
+        amplifier
+    }).collect::<Vec<usize>>();
+
+    if feedback {
+        network.ring(&amplifiers);
+    } else {
+        network.chain(&amplifiers);
+    }
+    network.send(amplifiers[0], 0);
+    if let Outcome::Deadlock(waiting) = network.run().expect("intcode program failed") {
+        panic!("amplifiers {:?} are stuck waiting for input", waiting)
+    }
+
+    *network.output(*amplifiers.last().unwrap()).last().expect("the last amplifier sent no signal")
+}
+
+pub fn find_optimal_feedback_loop_settings (code: &[i64]) -> i64 {
+    let possible_phases = (5..10).collect();
+    let possible_settings = permutate(possible_phases);
+    possible_settings.iter().map(|settings| calculate_feedback_loop_output(code, settings)).max().unwrap()
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (code: &Vec<i64>) -> i64 {
+        find_optimal_settings(code)
+    }
+
+    fn part2 (code: &Vec<i64>) -> i64 {
+        find_optimal_feedback_loop_settings(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_thruster_signal () {
+        assert_eq!(find_optimal_settings(&Solution::parse("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0")), 43210);
+        assert_eq!(find_optimal_settings(&Solution::parse("3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0")), 54321);
+        assert_eq!(find_optimal_settings(&Solution::parse("3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0")), 65210);
+    }
+
+    #[test]
+    fn max_feedback_loop_signal () {
+        assert_eq!(find_optimal_feedback_loop_settings(&Solution::parse("3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5")), 139629729);
+        assert_eq!(find_optimal_feedback_loop_settings(&Solution::parse("3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10")), 18216);
+    }
+
+    #[test]
+    #[should_panic(expected = "amplifiers [0, 1, 2, 3, 4] are stuck waiting for input")]
+    fn deadlocked_amplifiers () {
+        // wants a third input that never comes
+        calculate_output(&[3, 0, 3, 0, 3, 0, 99], &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn settings_in_order () {
+        assert_eq!(calculate_output(&Solution::parse("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0"), &[4, 3, 2, 1, 0]), 43210);
+    }
+}
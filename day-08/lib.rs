@@ -0,0 +1,100 @@
+use solver::Solver;
+
+pub static IMAGE_SIZE: (usize, usize) = (25, 6);
+
+fn get_layers (file: &str, size: &(usize, usize)) -> Vec<String> {
+    file
+        .chars()
+        .collect::<Vec<char>>()
+        .chunks(size.0 * size.1)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect()
+}
+
+fn count_color (layer: &str, color: i32) -> i32 {
+    let mut count = 0;
+    for pixel in layer.chars() {
+        if parse_digit(&pixel) == color {
+            count += 1
+        }
+    }
+    count
+}
+
+pub fn calculate_checksum (file: &str, size: &(usize, usize)) -> i32 {
+    let layers = get_layers(file, size);
+    let layer = layers.iter().min_by_key(|layer| count_color(layer, 0)).unwrap();
+
+    count_color(layer, 1) * count_color(layer, 2)
+}
+
+pub fn decode_image (file: &str, size: &(usize, usize)) -> String {
+    let mut image = vec![2; size.0 * size.1];
+    let layers = get_layers(file, size);
+
+    for layer in layers {
+        for (i, pixel) in layer.chars().enumerate() {
+            match image[i] {
+                2 => image[i] = parse_digit(&pixel),
+                0 | 1 => {},
+                _ => panic!("unkown color")
+            }
+        }
+    }
+
+    image
+        .chunks(size.0)
+        .map(|chunk| chunk
+            .iter()
+            .map(|pixel| match pixel {
+                0 => String::from("░"),
+                1 => String::from("█"),
+                2 => String::from("▒"),
+                _ => panic!("unkown color")
+            })
+            .collect::<Vec<String>>()
+            .join("")
+        )
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn parse_digit (char: &char) -> i32 {
+    char.to_digit(10).expect("cannot parse char as digit") as i32
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = &'a str;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse (input: &str) -> &str {
+        input.trim()
+    }
+
+    fn part1 (image: &&str) -> i32 {
+        calculate_checksum(image, &IMAGE_SIZE)
+    }
+
+    fn part2 (image: &&str) -> String {
+        decode_image(image, &IMAGE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_of_layer_with_fewest_zeros () {
+        // layers 123456 and 789012, the first has no zeros
+        assert_eq!(calculate_checksum("123456789012", &(3, 2)), 1);
+    }
+
+    #[test]
+    fn stacks_layers () {
+        assert_eq!(decode_image("0222112222120000", &(2, 2)), "░█\n█░");
+    }
+}
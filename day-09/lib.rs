@@ -0,0 +1,42 @@
+use solver::Solver;
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+	type Input<'a> = Vec<i64>;
+	type Answer1 = i64;
+	type Answer2 = i64;
+
+	fn parse (input: &str) -> Vec<i64> {
+		input.trim().split(',').map(parse_number).collect()
+	}
+
+	fn part1 (tape: &Vec<i64>) -> i64 {
+		intcode::run(tape, &[1]).expect("intcode program failed").pop().expect("no BOOST keycode")
+	}
+
+	fn part2 (tape: &Vec<i64>) -> i64 {
+		intcode::run(tape, &[2]).expect("intcode program failed").pop().expect("no coordinates")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn copies_itself () {
+		let quine = Solution::parse("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+		assert_eq!(intcode::run(&quine, &[]).unwrap(), quine);
+	}
+
+	#[test]
+	fn large_numbers () {
+		assert_eq!(intcode::run(&Solution::parse("1102,34915192,34915192,7,4,7,99,0"), &[]).unwrap(), vec![1219070632396864]);
+		assert_eq!(intcode::run(&Solution::parse("104,1125899906842624,99"), &[]).unwrap(), vec![1125899906842624]);
+	}
+}
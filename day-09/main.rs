@@ -1,18 +1,16 @@
 use std::fs;
-mod intcode;
 
-fn parse_number (string: &str) -> i64 {
-	string.parse::<i64>().expect("cannot parse string as int")
-}
+use solver::Solver;
+use day_09::Solution;
 
 fn main () {
 	let file = fs::read_to_string("./input.txt").expect("unable to download file");
-	let data = file.trim().split("\n").map(parse_number).collect();
+	let data = Solution::parse(&file);
 
     println!(
 		"star 9-1: {:?}
 star 9-2: {:?}",
-		intcode::run(&data, &vec![1]),
-		intcode::run(&data, &vec![2])
+		intcode::run(&data, &[1]).expect("intcode program failed"),
+		intcode::run(&data, &[2]).expect("intcode program failed")
 	);
 }
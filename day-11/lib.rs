@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::cmp;
+
+use solver::Solver;
+
+enum Color {
+    White,
+    Black
+}
+
+impl From<i64> for Color {
+    fn from (number: i64) -> Self {
+        match number {
+            0 => Color::Black,
+            1 => Color::White,
+            _ => panic!("unknown color")
+        }
+    }
+}
+
+impl From<&Color> for i64 {
+    fn from (color: &Color) -> Self {
+        match color {
+            Color::Black => 0,
+            Color::White => 1
+        }
+    }
+}
+
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+fn turn_left (direction: &Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Left,
+        Direction::Right => Direction::Up,
+        Direction::Down => Direction::Right,
+        Direction::Left => Direction::Down
+    }
+}
+
+fn turn_right (direction: &Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Right,
+        Direction::Right => Direction::Down,
+        Direction::Down => Direction::Left,
+        Direction::Left => Direction::Up
+    }
+}
+
+fn move_robot (position: &mut (i64, i64), direction: &Direction) {
+    match direction {
+        Direction::Up => position.1 -= 1,
+        Direction::Right => position.0 += 1,
+        Direction::Down => position.1 += 1,
+        Direction::Left => position.0 -= 1,
+    }
+}
+
+struct Robot {
+    panels: HashMap<(i64, i64), Color>,
+    position: (i64, i64),
+    direction: Direction,
+    painted: bool
+}
+
+impl intcode::io::Input for Robot {
+    fn read (&mut self) -> Option<i64> {
+        let panel = self.panels.get(&self.position).unwrap_or(&Color::Black);
+        Some(panel.into())
+    }
+}
+
+impl intcode::io::Output for Robot {
+    fn write (&mut self, value: i64) {
+        if !self.painted {
+            self.panels.insert(self.position, Color::from(value));
+        } else {
+            self.direction = if value == 1 {
+                turn_right(&self.direction)
+            } else {
+                turn_left(&self.direction)
+            };
+            move_robot(&mut self.position, &self.direction);
+        }
+        self.painted = !self.painted;
+    }
+}
+
+fn paint_panels (intcode: &[i64], start_color: Color) -> HashMap<(i64, i64), Color> {
+    let mut state = intcode::ProgramState::new(intcode);
+    let mut robot = Robot {
+        panels: HashMap::new(),
+        position: (0, 0),
+        direction: Direction::Up,
+        painted: false
+    };
+
+    robot.panels.insert(robot.position, start_color);
+    intcode::io::run_device(&mut state, &mut robot).expect("intcode program failed");
+
+    robot.panels
+}
+
+pub fn count_painted_panels (intcode: &[i64]) -> usize {
+    let panels = paint_panels(intcode, Color::Black);
+    panels.len()
+}
+
+pub fn display_panels (intcode: &[i64]) -> String {
+    let panels = paint_panels(intcode, Color::White);
+    let mut min = (0, 0);
+    let mut max = (0, 0);
+
+    for point in panels.keys() {
+        min.0 = cmp::min(min.0, point.0);
+        min.1 = cmp::min(min.1, point.1);
+        max.0 = cmp::max(max.0, point.0);
+        max.1 = cmp::max(max.1, point.1);
+    }
+
+    let mut display = String::new();
+
+    for y in min.1..=max.1 {
+        for x in min.0..=max.0 {
+            let color = panels.get(&(x, y)).unwrap_or(&Color::Black);
+            match color {
+                Color::White => display += "█",
+                Color::Black => display += "░"
+            }
+        }
+        display += "\n"
+    }
+
+    display
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (intcode: &Vec<i64>) -> usize {
+        count_painted_panels(intcode)
+    }
+
+    fn part2 (intcode: &Vec<i64>) -> String {
+        display_panels(intcode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a program that gives the example's colors and turns, whatever it reads
+    fn example_program () -> Vec<i64> {
+        let mut tape = intcode::assembler::outputs(&[1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0]);
+        tape.push(99);
+        tape
+    }
+
+    // Langton's ant for 11 steps: flips the color it reads, and turns left
+    // on black and right on white
+    fn ant_program () -> Vec<i64> {
+        let source = "
+            loop:   in   color
+                    eq   color, #0, paint
+                    out  paint
+                    out  color
+                    add  steps, #-1, steps
+                    jt   steps, #loop
+                    hlt
+            color:  db   0
+            paint:  db   0
+            steps:  db   11
+        ";
+        intcode::assembler::assemble(source).unwrap()
+    }
+
+    #[test]
+    fn counts_painted_panels () {
+        assert_eq!(count_painted_panels(&example_program()), 6);
+        assert_eq!(count_painted_panels(&ant_program()), 8);
+    }
+
+    #[test]
+    fn displays_white_panels () {
+        assert_eq!(display_panels(&example_program()), "░░█\n░░█\n██░\n");
+        // starting on white turns the ant the other way
+        assert_eq!(display_panels(&ant_program()), "██░\n███\n░░█\n");
+    }
+}
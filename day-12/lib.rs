@@ -0,0 +1,168 @@
+use solver::Solver;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Body {
+    x: (i64, i64),
+    y: (i64, i64),
+    z: (i64, i64)
+}
+
+impl From<&str> for Body {
+    fn from (string: &str) -> Self {
+        let pairs = string[1..(string.len() - 1)].split(", ").collect::<Vec<&str>>();
+        let x = pairs[0][2..].parse::<i64>().expect("cannot parse value");
+        let y = pairs[1][2..].parse::<i64>().expect("cannot parse value");
+        let z = pairs[2][2..].parse::<i64>().expect("cannot parse value");
+        Body {
+            x: (x, 0),
+            y: (y, 0),
+            z: (z, 0)
+        }
+    }
+}
+
+impl Body {
+    fn get_total_energy (self) -> u64 {
+        let pot = self.x.0.abs() + self.y.0.abs() + self.z.0.abs();
+        let kin = self.x.1.abs() + self.y.1.abs() + self.z.1.abs();
+        (pot * kin) as u64
+    }
+
+    fn get_velocity_diff (&self, other: &Body) -> (i64, i64, i64) {
+        let x = (other.x.0 - self.x.0).signum();
+        let y = (other.y.0 - self.y.0).signum();
+        let z = (other.z.0 - self.z.0).signum();
+        (x, y, z)
+    }
+}
+
+fn step (bodies: &mut Vec<Body>) {
+    let len = bodies.len();
+    for i in 0..len {
+        for j in 0..len {
+            if i != j {
+                let (x, y, z) = bodies[i].get_velocity_diff(&bodies[j]);
+                let body = &mut bodies[i];
+                body.x.1 += x;
+                body.y.1 += y;
+                body.z.1 += z;
+            }
+        }
+    }
+
+    for body in bodies {
+        body.x.0 += body.x.1;
+        body.y.0 += body.y.1;
+        body.z.0 += body.z.1;
+    }
+}
+
+fn steps (bodies: &mut Vec<Body>, n_steps: u64) {
+    for _ in 0..n_steps {
+        step(bodies)
+    }
+}
+
+pub fn calculate_energy (bodies: &[Body], n_steps: u64) -> u64 {
+    let mut state = bodies.to_vec();
+    steps(&mut state, n_steps);
+
+    state.iter().map(|body| body.get_total_energy()).sum()
+}
+
+fn gcd (a: u64, b: u64) -> u64 {
+    let mut a = a;
+    let mut b = b;
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+fn lcm (a: u64, b: u64) -> u64 {
+    (a * b) / gcd(a, b)
+}
+
+// with `verbose`, counts the steps on stderr as it goes
+pub fn calculate_steps (bodies: &[Body], verbose: bool) -> u64 {
+    let len = bodies.len();
+    let mut state = bodies.to_vec();
+    let mut steps = (None, None, None);
+    let mut counter = 0;
+    if verbose { eprintln!(); }
+
+    loop {
+        step(&mut state);
+        counter += 1;
+        if verbose { eprintln!("\x1B[1A{} steps", counter); }
+
+        let mut same = (true, true, true);
+        for i in 0..len {
+            let a = state[i];
+            let b = bodies[i];
+            if a.x != b.x { same.0 = false; }
+            if a.y != b.y { same.1 = false; }
+            if a.z != b.z { same.2 = false; }
+        }
+
+        if steps.0.is_none() && same.0 {
+            if verbose { eprintln!("\x1B[1Ax in {} steps\n", counter); }
+            steps.0 = Some(counter);
+        }
+        if steps.1.is_none() && same.1 {
+            if verbose { eprintln!("\x1B[1Ay in {} steps\n", counter); }
+            steps.1 = Some(counter);
+        }
+        if steps.2.is_none() && same.2 {
+            if verbose { eprintln!("\x1B[1Az in {} steps\n", counter); }
+            steps.2 = Some(counter);
+        }
+
+        if let (Some(x), Some(y), Some(z)) = steps {
+            if verbose { eprintln!("\x1B[2A"); }
+            return lcm(lcm(x, y), z)
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Body>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse (input: &str) -> Vec<Body> {
+        input.trim().split('\n').map(Body::from).collect()
+    }
+
+    fn part1 (bodies: &Vec<Body>) -> u64 {
+        calculate_energy(bodies, 1000)
+    }
+
+    fn part2 (bodies: &Vec<Body>) -> u64 {
+        calculate_steps(bodies, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static FIRST: &str = "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>";
+    static SECOND: &str = "<x=-8, y=-10, z=0>\n<x=5, y=5, z=10>\n<x=2, y=-7, z=3>\n<x=9, y=-8, z=-3>";
+
+    #[test]
+    fn total_energy () {
+        assert_eq!(calculate_energy(&Solution::parse(FIRST), 10), 179);
+        assert_eq!(calculate_energy(&Solution::parse(SECOND), 100), 1940);
+    }
+
+    #[test]
+    fn steps_until_repeat () {
+        assert_eq!(calculate_steps(&Solution::parse(FIRST), false), 2772);
+        assert_eq!(calculate_steps(&Solution::parse(SECOND), false), 4686774924);
+    }
+}
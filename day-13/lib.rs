@@ -0,0 +1,172 @@
+use std::cmp;
+use std::fmt;
+use std::collections::HashMap;
+
+use solver::Solver;
+
+#[derive(Default)]
+struct Game {
+    ball: (i64, i64),
+    paddle: (i64, i64),
+    tiles: HashMap<(i64, i64), i64>,
+    size: (i64, i64)
+}
+
+impl Game {
+    fn count_tiles (&self, tile_type: i64) -> i64 {
+        let mut sum = 0;
+        for tile in self.tiles.values() {
+            if *tile == tile_type {
+                sum += 1;
+            }
+        }
+        sum
+    }
+
+    fn update_tile (&mut self, tile: &[i64]) {
+        self.tiles.insert((tile[0], tile[1]), tile[2]);
+
+        match tile[2] {
+            3 => { self.paddle = (tile[0], tile[1]); },
+            4 => { self.ball = (tile[0], tile[1]); },
+            _ => {}
+        };
+    }
+
+    fn update (&mut self, output: Vec<i64>) {
+        let tiles = output.chunks(3);
+        for tile in tiles {
+            self.update_tile(tile);
+        }
+    }
+}
+
+impl From<Vec<i64>> for Game {
+    fn from (output: Vec<i64>) -> Self {
+        let mut game: Game = Default::default();
+        let tiles = output.chunks(3);
+
+        let mut size = (0, 0);
+        for tile in tiles {
+            size.0 = cmp::max(size.0, tile[0]);
+            size.1 = cmp::max(size.1, tile[1]);
+            game.update_tile(tile);
+        }
+
+        game.size = size;
+
+        game
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut display = String::new();
+
+        for y in 0..=self.size.1 {
+            for x in 0..=self.size.0 {
+                let char = match self.tiles.get(&(x, y)) {
+                    Some(1) => '#',
+                    Some(2) => '+',
+                    Some(3) => '=',
+                    Some(4) => 'o',
+                    _ => ' '
+                };
+                display.push(char);
+            }
+
+            if y == 0 {
+                display += &format!(" {:?}", self.tiles.get(&(-1, 0)));
+            }
+
+            display += &" ".repeat(20);
+            display.push('\n');
+        }
+
+        write!(f, "{}", display)
+    }
+}
+
+pub fn star_13_1 (tape: &[i64]) -> i64 {
+    let game = Game::from(intcode::run(tape, &[]).expect("intcode program failed"));
+    game.count_tiles(2)
+}
+
+// with `verbose`, draws the game on stderr as it is played
+pub fn play_game (tape: &[i64], verbose: bool) -> Option<i64> {
+    let mut state = intcode::ProgramState {
+        tape: tape.to_vec(),
+        ..Default::default()
+    };
+    state.tape[0] = 2;
+
+    let mut game = Game::from(intcode::step_input(&mut state, None).expect("intcode program failed"));
+    if verbose {
+        eprintln!("{}", game);
+    }
+
+    loop {
+        let prev_size = game.size.1;
+        let position = (game.ball.0 - game.paddle.0).signum();
+        game.update(intcode::step_input(&mut state, Some(position)).expect("intcode program failed"));
+
+        if verbose {
+            eprintln!("\x1B[{}A{}", prev_size + 2, game);
+        }
+
+        if game.count_tiles(2) == 0 {
+            let score = game.tiles.get(&(-1, 0));
+            return score.copied()
+        }
+    }
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (tape: &Vec<i64>) -> i64 {
+        star_13_1(tape)
+    }
+
+    fn part2 (tape: &Vec<i64>) -> i64 {
+        play_game(tape, false).expect("game did not end")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use intcode::assembler::outputs;
+
+    #[test]
+    fn counts_block_tiles () {
+        // the example's paddle and ball, with two blocks
+        let mut tape = outputs(&[1, 2, 3, 6, 5, 4, 2, 0, 2, 3, 0, 2]);
+        tape.push(99);
+        assert_eq!(star_13_1(&tape), 2);
+    }
+
+    #[test]
+    fn plays_until_no_blocks_are_left () {
+        // address 0 is where the quarters go, then one block is hit after
+        // the first joystick move
+        let mut tape = vec![1, 0, 0, 0];
+        tape.extend(outputs(&[-1, 0, 0, 1, 2, 3, 6, 5, 4, 5, 4, 2]));
+        tape.extend(&[3, 0]);
+        tape.extend(outputs(&[5, 4, 0, -1, 0, 17]));
+        tape.push(99);
+        assert_eq!(play_game(&tape, false), Some(17));
+    }
+}
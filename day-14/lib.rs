@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use solver::Solver;
+
+#[derive(Debug)]
+struct Chemical <'a> {
+    name: &'a str,
+    quantity: u64
+}
+
+impl <'a> From<&'a str> for Chemical<'a> {
+    fn from (string: &'a str) -> Self {
+        let parts = string.split(" ").collect::<Vec<&str>>();
+        Chemical {
+            quantity: parts[0].parse::<u64>().expect("cannot parse integer"),
+            name: parts[1]
+        }
+    }
+}
+
+pub struct Reaction <'a> {
+    input: Vec<Chemical<'a>>,
+    output: Chemical<'a>
+}
+
+impl <'a> From<&'a str> for Reaction<'a> {
+    fn from (string: &'a str) -> Self {
+        let parts = string.split(" => ").collect::<Vec<&str>>();
+        let input = parts[0].split(", ").map(Chemical::from).collect();
+        Reaction {
+            input,
+            output: Chemical::from(parts[1])
+        }
+    }
+}
+
+pub fn calculate_needed_ore <'a> (reactions: &'a HashMap<&str, Reaction>, leftovers: &mut HashMap<&'a str, u64>, product_name: &'a str, quantity: u64) -> u64 {
+    if product_name == "ORE" {
+        quantity
+    } else if leftovers.contains_key(product_name) {
+        let leftover = leftovers.remove(product_name).unwrap();
+        if quantity > leftover {
+            calculate_needed_ore(reactions, leftovers, product_name, quantity - leftover)
+        } else if leftover > quantity {
+            leftovers.insert(product_name, leftover - quantity);
+            0
+        } else {
+            0
+        }
+    } else {
+        let mut total_ore = 0;
+        let reaction = reactions.get(product_name).unwrap();
+        let factor = 1 + ((quantity - 1) / reaction.output.quantity);
+
+        for chemical in &reaction.input {
+            let ore = calculate_needed_ore(reactions, leftovers, chemical.name, chemical.quantity * factor);
+            total_ore += ore;
+        }
+
+        let leftover_product = reaction.output.quantity * factor - quantity;
+        if leftover_product > 0 {
+            *leftovers.entry(product_name).or_insert(0) += leftover_product;
+        }
+
+        total_ore
+    }
+}
+
+pub fn calculate_maximum_fuel (reactions: &HashMap<&str, Reaction>, maximum_ore: u64) -> u64 {
+    let mut lower_bound = 1;
+    let mut upper_bound = maximum_ore;
+
+    loop {
+        let fuel = (lower_bound + upper_bound) / 2;
+        let ore = calculate_needed_ore(reactions, &mut HashMap::new(), "FUEL", fuel);
+        if ore < maximum_ore {
+            if lower_bound == fuel {
+                break
+            } else {
+                lower_bound = fuel;
+            }
+        } else {
+            upper_bound = fuel
+        }
+    }
+
+    lower_bound
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = HashMap<&'a str, Reaction<'a>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse (input: &str) -> HashMap<&str, Reaction<'_>> {
+        let mut lookup = HashMap::new();
+        for reaction in input.trim().split('\n').map(Reaction::from) {
+            lookup.insert(reaction.output.name, reaction);
+        }
+        lookup
+    }
+
+    fn part1 (reactions: &HashMap<&str, Reaction>) -> u64 {
+        calculate_needed_ore(reactions, &mut HashMap::new(), "FUEL", 1)
+    }
+
+    fn part2 (reactions: &HashMap<&str, Reaction>) -> u64 {
+        calculate_maximum_fuel(reactions, 1_000_000_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static LARGE: &str = "\
+157 ORE => 5 NZVS
+165 ORE => 6 DCFZ
+44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+179 ORE => 7 PSHF
+177 ORE => 5 HKGWZ
+7 DCFZ, 7 PSHF => 2 XJWVT
+165 ORE => 2 GPVTF
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT";
+
+    fn ore_for_one_fuel (reactions: &str) -> u64 {
+        calculate_needed_ore(&Solution::parse(reactions), &mut HashMap::new(), "FUEL", 1)
+    }
+
+    #[test]
+    fn ore_for_fuel () {
+        assert_eq!(ore_for_one_fuel("\
+10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL"), 31);
+        assert_eq!(ore_for_one_fuel("\
+9 ORE => 2 A
+8 ORE => 3 B
+7 ORE => 5 C
+3 A, 4 B => 1 AB
+5 B, 7 C => 1 BC
+4 C, 1 A => 1 CA
+2 AB, 3 BC, 4 CA => 1 FUEL"), 165);
+        assert_eq!(ore_for_one_fuel(LARGE), 13312);
+    }
+
+    #[test]
+    fn fuel_for_a_trillion_ore () {
+        assert_eq!(calculate_maximum_fuel(&Solution::parse(LARGE), 1_000_000_000_000), 82892753);
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::cmp;
+
+use solver::Solver;
+
+enum Direction {
+    North,
+    East,
+    South,
+    West
+}
+
+impl From<&Direction> for i64 {
+    fn from (direction: &Direction) -> Self {
+        match direction {
+            Direction::North => 1,
+            Direction::East => 4,
+            Direction::South => 2,
+            Direction::West => 3
+        }
+    }
+}
+
+impl From<&Direction> for &str {
+    fn from (direction: &Direction) -> Self {
+        match direction {
+            Direction::North => "^^",
+            Direction::East => ">>",
+            Direction::South => "vv",
+            Direction::West => "<<"
+        }
+    }
+}
+
+fn turn_left (direction: &Direction) -> Direction {
+    match direction {
+        Direction::North => Direction::West,
+        Direction::East => Direction::North,
+        Direction::South => Direction::East,
+        Direction::West => Direction::South
+    }
+}
+
+fn turn_right (direction: &Direction) -> Direction {
+    match direction {
+        Direction::North => Direction::East,
+        Direction::East => Direction::South,
+        Direction::South => Direction::West,
+        Direction::West => Direction::North
+    }
+}
+
+fn move_drone (pos: &(i64, i64), direction: &Direction) -> (i64, i64) {
+    match direction {
+        Direction::North => (pos.0, pos.1 - 1),
+        Direction::East => (pos.0 + 1, pos.1),
+        Direction::South => (pos.0, pos.1 + 1),
+        Direction::West => (pos.0 - 1, pos.1)
+    }
+}
+
+fn find_dimensions (map: &HashMap<(i64, i64), i64>) -> ((i64, i64), (i64, i64)) {
+    let mut min = (0, 0);
+    let mut max = (0, 0);
+    for (x, y) in map.keys() {
+        min.0 = cmp::min(*x, min.0);
+        min.1 = cmp::min(*y, min.1);
+        max.0 = cmp::max(*x, max.0);
+        max.1 = cmp::max(*y, max.1);
+    }
+    (min, max)
+}
+
+// draws the map on stderr, over the previous drawing with `up`
+pub fn display (map: &HashMap<(i64, i64), i64>, up: bool) {
+    let (min, max) = find_dimensions(map);
+    let mut display = String::new();
+    if up {
+        display += "\x1B[";
+        display += &((max.1 - min.1 + 2).to_string() + "A");
+    }
+
+    for y in min.1..=max.1 {
+        for x in min.0..=max.0 {
+            if (x, y) == (0, 0) {
+                display += "\x1B[32m\x1B[42mSS\x1B[0m"
+            } else {
+                match map.get(&(x, y)) {
+                    Some(0) => display += "\x1B[47m##\x1B[0m",
+                    Some(1) => display += "..",
+                    Some(2) => display += "\x1B[33m\x1B[43mEE\x1B[0m",
+                    Some(3) => display += "\x1B[34m\x1B[44mOO\x1B[0m",
+                    None => display += "  ",
+                    Some(_) => panic!("unknown status")
+                }
+            }
+        }
+        display += "\n";
+    }
+
+    eprintln!("{}", display);
+}
+
+struct Droid {
+    map: HashMap<(i64, i64), i64>,
+    position: (i64, i64),
+    direction: Direction,
+    done: bool
+}
+
+impl intcode::io::Input for Droid {
+    fn read (&mut self) -> Option<i64> {
+        if self.done {
+            None
+        } else {
+            Some((&self.direction).into())
+        }
+    }
+}
+
+impl intcode::io::Output for Droid {
+    fn write (&mut self, ahead: i64) {
+        let checking = move_drone(&self.position, &self.direction);
+        self.map.insert(checking, ahead);
+
+        match ahead {
+            0 => self.direction = turn_left(&self.direction),
+            1 | 2 => {
+                self.position = checking;
+
+                if self.position == (0, 0) {
+                    self.done = true;
+                    return
+                }
+
+                let right_direction = turn_right(&self.direction);
+                let right = self.map.get(&move_drone(&self.position, &right_direction));
+                if right.is_none() || *right.unwrap() == 1 {
+                    self.direction = right_direction;
+                }
+            },
+            _ => panic!("unknown status")
+        }
+    }
+}
+
+pub fn build_map (tape: &[i64]) -> HashMap<(i64, i64), i64> {
+    let mut state = intcode::ProgramState::new(tape);
+    let mut droid = Droid {
+        map: HashMap::new(),
+        position: (0, 0),
+        direction: Direction::North,
+        done: false
+    };
+
+    intcode::io::run_device(&mut state, &mut droid).expect("intcode program failed");
+
+    droid.map
+}
+
+pub fn find_system (map: &HashMap<(i64, i64), i64>) -> (i64, (i64, i64)) {
+    let mut moves = HashMap::new();
+    let mut move_number = 0;
+    let mut position = (0, 0);
+    let mut direction = Direction::North;
+
+    loop {
+        let checking = move_drone(&position, &direction);
+
+        match map[&checking] {
+            0 => direction = turn_left(&direction),
+            1 => {
+                position = checking;
+
+                if moves.contains_key(&position) {
+                    move_number = moves[&position];
+                } else {
+                    move_number += 1;
+                }
+                moves.insert(position, move_number);
+
+                let right_direction = turn_right(&direction);
+                let right = map.get(&move_drone(&position, &right_direction));
+                if right.is_none() || *right.unwrap() == 1 {
+                    direction = right_direction;
+                }
+            },
+            2 => return (move_number + 1, checking),
+            _ => panic!("unknown status")
+        }
+    }
+}
+
+// with `verbose`, draws the oxygen spreading on stderr
+pub fn fill_oxygen (map: &mut HashMap<(i64, i64), i64>, system: &(i64, i64), verbose: bool) -> u64 {
+    let mut minutes = 0;
+    let mut leading_pos = vec![*system];
+
+    loop {
+        if verbose {
+            display(map, true);
+        }
+
+        let mut new = Vec::new();
+        for pos in leading_pos {
+            static DIRECTIONS: [Direction; 4] = [Direction::North, Direction::West, Direction::East, Direction::South];
+            for dir in DIRECTIONS.iter() {
+                let next = move_drone(&pos, dir);
+                let check = map.get(&next);
+                if check.is_some() && *check.unwrap() == 1 {
+                    map.insert(next, 3);
+                    new.push(next);
+                }
+            }
+        }
+
+        if new.is_empty() {
+            return minutes
+        } else {
+            leading_pos = new;
+            minutes += 1;
+        }
+    }
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = u64;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (tape: &Vec<i64>) -> i64 {
+        find_system(&build_map(tape)).0
+    }
+
+    fn part2 (tape: &Vec<i64>) -> u64 {
+        let mut map = build_map(tape);
+        let (_, system) = find_system(&map);
+        fill_oxygen(&mut map, &system, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the oxygen example, with the droid starting top left
+    fn example_map () -> HashMap<(i64, i64), i64> {
+        let rows = [" ##   ", "#..## ", "#.#..#", "#.O.# ", " ###  "];
+        let mut map = HashMap::new();
+        for (y, row) in rows.iter().enumerate() {
+            for (x, char) in row.chars().enumerate() {
+                let status = match char {
+                    '#' => 0,
+                    '.' => 1,
+                    'O' => 2,
+                    _ => continue
+                };
+                map.insert((x as i64 - 1, y as i64 - 1), status);
+            }
+        }
+        map
+    }
+
+    #[test]
+    fn finds_oxygen_system () {
+        assert_eq!(find_system(&example_map()), (3, (1, 2)));
+    }
+
+    #[test]
+    fn fills_with_oxygen () {
+        assert_eq!(fill_oxygen(&mut example_map(), &(1, 2), false), 4);
+    }
+}
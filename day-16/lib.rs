@@ -0,0 +1,71 @@
+use solver::{Solver, Unsolved};
+
+fn get_multiplier (index: usize, factor: usize) -> i32 {
+    static MULTIPLIERS: [i32; 4] = [0, 1, 0, -1];
+    let index = ((index + 1) / (factor + 1)) % 4;
+    MULTIPLIERS[index]
+}
+
+fn phase_signal (input: &[u32]) -> Vec<u32> {
+    let mut output = Vec::new();
+    for i in 0..input.len() {
+        let sum: i32 = input
+            .iter()
+            .enumerate()
+            .map(|(j, &digit)| (digit as i32) * get_multiplier(j, i))
+            .sum();
+        output.push(sum.unsigned_abs() % 10)
+    }
+    output
+}
+
+pub fn star_16_1 (input: &[u32], n: usize) -> String {
+    let mut output = input.to_vec();
+    for _ in 0..n {
+        output = phase_signal(&output)
+    }
+    output[0..8].iter().map(|digit| digit.to_string()).collect::<Vec<String>>().join("")
+}
+
+fn parse_number (char: char) -> u32 {
+	char.to_digit(10).expect("cannot parse char as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<u32>;
+    type Answer1 = String;
+    type Answer2 = Unsolved;
+
+    fn parse (input: &str) -> Vec<u32> {
+        input.trim().chars().map(parse_number).collect()
+    }
+
+    fn part1 (signal: &Vec<u32>) -> String {
+        star_16_1(signal, 100)
+    }
+
+    fn part2 (_: &Vec<u32>) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases () {
+        let signal = Solution::parse("12345678");
+        assert_eq!(phase_signal(&signal), vec![4, 8, 2, 2, 6, 1, 5, 8]);
+        assert_eq!(star_16_1(&signal, 4), "01029498");
+    }
+
+    #[test]
+    fn first_digits_after_100_phases () {
+        assert_eq!(star_16_1(&Solution::parse("80871224585914546619083218645595"), 100), "24176176");
+        assert_eq!(star_16_1(&Solution::parse("19617804207202209144916044189917"), 100), "73745418");
+        assert_eq!(star_16_1(&Solution::parse("69317163492948606335995924319873"), 100), "52432133");
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use solver::{Solver, Unsolved};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Direction {
+    Up,
+    Right,
+    Left,
+    Down
+}
+
+static DIRECTIONS: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction:: Left];
+
+impl Direction {
+    fn turn_around (&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right
+        }
+    }
+
+    fn move_position (&self, pos: &Position) -> Position {
+        match self {
+            Direction::Up => Position(pos.0, pos.1 - 1),
+            Direction::Right => Position(pos.0 + 1, pos.1),
+            Direction::Down => Position(pos.0, pos.1 + 1),
+            Direction::Left => Position(pos.0 - 1, pos.1)
+        }
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+struct Position (u64, u64);
+
+pub struct Maze {
+    paths: HashSet<Position>,
+    objects: HashMap<Position, char>,
+    start: Position,
+    keys: usize
+}
+
+impl From<String> for Maze {
+    fn from (string: String) -> Self {
+        let mut paths = HashSet::new();
+        let mut objects = HashMap::new();
+        let mut start = Position(0, 0);
+        let mut keys = 0;
+        let mut x = 1;
+        let mut y = 1;
+
+        for char in string.chars() {
+            let pos = Position(x, y);
+            match char {
+                '#' => {},
+                '\n' => { x = 1; y += 1; },
+                '.' => {
+                    paths.insert(pos);
+                },
+                '@' => {
+                    paths.insert(pos);
+                    start = pos;
+                },
+                _ => {
+                    paths.insert(pos);
+
+                    objects.insert(pos, char);
+                    if char.is_ascii_lowercase() {
+                        keys += 1;
+                    }
+                }
+            }
+
+            if char != '\n' {
+                x += 1;
+            }
+        }
+
+        Maze { paths, objects, start, keys }
+    }
+}
+
+#[derive(Debug)]
+struct Searcher {
+    pos: Position,
+    dir: Option<Direction>,
+    dist: usize,
+    keys: Vec<char>
+}
+
+// with `verbose`, shows the search on stderr as it goes
+pub fn solve_maze (maze: &Maze, verbose: bool) -> Option<usize> {
+    let mut searchers = VecDeque::new();
+
+    searchers.push_back(Searcher {
+        pos: maze.start,
+        dir: None,
+        dist: 0,
+        keys: Vec::new()
+    });
+
+    if verbose {
+        eprintln!();
+    }
+
+    loop {
+        if searchers.is_empty() {
+            return None
+        }
+
+        let searcher = searchers.pop_front().unwrap();
+
+        if searcher.keys.len() == maze.keys {
+            return Some(searcher.dist)
+        } else {
+            for dir in &DIRECTIONS {
+
+                if searcher.dir.is_some() && *dir == searcher.dir.unwrap().turn_around() {
+                    continue
+                }
+
+                let new_pos = dir.move_position(&searcher.pos);
+                let mut new_dir = Some(*dir);
+                let mut new_keys = searcher.keys.to_vec();
+
+                if !maze.paths.contains(&new_pos) {
+                    continue
+                }
+
+                if maze.objects.contains_key(&new_pos) {
+                    let object = maze.objects[&new_pos];
+                    if verbose {
+                        eprintln!("\x1B[1A{: >5} searchers {: >4} dist {: >2} keys on {}   ", searchers.len() + 1, searcher.dist, searcher.keys.len(), object);
+                    }
+
+                    if object.is_ascii_lowercase() && !new_keys.contains(&object) {
+                        new_keys.push(object);
+                        new_dir = None;
+                    } else if object.is_ascii_uppercase() && !new_keys.contains(&object.to_ascii_lowercase()) {
+                        continue
+                    }
+                }
+
+                searchers.push_back(Searcher {
+                    pos: new_pos,
+                    dir: new_dir,
+                    dist: searcher.dist + 1,
+                    keys: new_keys
+                });
+            }
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Maze;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse (input: &str) -> Maze {
+        Maze::from(input.to_string())
+    }
+
+    fn part1 (maze: &Maze) -> usize {
+        solve_maze(maze, false).expect("no path found")
+    }
+
+    fn part2 (_: &Maze) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_keys () {
+        assert_eq!(solve_maze(&Solution::parse("\
+#########
+#b.A.@.a#
+#########"), false), Some(8));
+        assert_eq!(solve_maze(&Solution::parse("\
+########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################"), false), Some(86));
+        assert_eq!(solve_maze(&Solution::parse("\
+########################
+#...............b.C.D.f#
+#.######################
+#.....@.a.B.c.d.A.e.F.g#
+########################"), false), Some(132));
+    }
+}
@@ -1 +0,0 @@
-../day-05/intcode.rs
\ No newline at end of file
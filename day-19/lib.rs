@@ -0,0 +1,134 @@
+use solver::Solver;
+
+struct Map <'a> {
+    tape: &'a [i64],
+    y: usize,
+    // show each row on stderr as it is checked
+    verbose: bool
+}
+
+impl <'a> Map <'a> {
+    fn new (tape: &'a [i64], verbose: bool) -> Self {
+        if verbose {
+            eprintln!();
+        }
+        Map {
+            tape,
+            y: 0,
+            verbose
+        }
+    }
+}
+
+impl Iterator for Map <'_> {
+    type Item = (usize, usize);
+
+    fn next (&mut self) -> Option<(usize, usize)> {
+        let mut offset = None;
+        let mut width = 0;
+
+        let mut x = 0;
+        loop {
+            let coord = intcode::run(
+                self.tape,
+                &[x as i64, self.y as i64]
+            ).expect("intcode program failed").pop().unwrap_or(0);
+            x += 1;
+
+            if coord == 1 {
+                width += 1;
+                if offset.is_none() {
+                    offset = Some(x);
+                }
+            } else if x >= self.y * 5 || offset.is_some() {
+                break
+            }
+        }
+
+        if self.verbose {
+            eprintln!("\x1B[1AChecking {} {:?} {}", self.y, offset, width);
+        }
+        self.y += 1;
+        Some((offset.unwrap_or(0), width))
+    }
+}
+
+pub fn star_19_1 (tape: &[i64], verbose: bool) -> usize {
+    Map::new(tape, verbose).take(50).map(|(_, width)| width).sum()
+}
+
+pub fn star_19_2 (tape: &[i64], verbose: bool) -> Option<usize> {
+    static SIZE: usize = 100;
+    let iter = Map::new(tape, verbose).enumerate();
+    let mut map = Vec::<(usize, usize)>::new();
+
+    for (y, end) in iter {
+        if end.1 >= SIZE && y >= SIZE {
+            let start = map[y - SIZE];
+            if (start.0 + start.1 - end.0) >= SIZE {
+                return Some(start.0 * 10000 + y)
+            }
+        }
+
+        map.push(end);
+    }
+
+    None
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (tape: &Vec<i64>) -> usize {
+        star_19_1(tape, false)
+    }
+
+    fn part2 (tape: &Vec<i64>) -> usize {
+        star_19_2(tape, false).expect("no square found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Reads x and y and outputs whether y / 2 <= x <= y, a beam that gets a
+    // point wider every other row. Row y has y / 2 + 1 points.
+    static BEAM: [i64; 32] = [
+        3, 27,
+        3, 28,
+        7, 28, 27, 29,
+        1002, 27, 2, 30,
+        7, 30, 28, 30,
+        1, 29, 30, 29,
+        8, 29, 31, 29,
+        4, 29,
+        99,
+        0, 0, 0, 0, 0
+    ];
+
+    #[test]
+    fn scans_rows () {
+        let rows = Map::new(&BEAM, false).take(6).collect::<Vec<(usize, usize)>>();
+        // offsets are one past the first x in the beam
+        assert_eq!(rows, vec![(1, 1), (2, 1), (2, 2), (3, 2), (3, 3), (4, 3)]);
+    }
+
+    #[test]
+    fn counts_affected_points () {
+        // twice 0 + 1 + ... + 24 for the halves, and 1 for every row
+        assert_eq!(star_19_1(&BEAM, false), 2 * 300 + 50);
+    }
+}
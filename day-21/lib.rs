@@ -0,0 +1,113 @@
+use solver::Solver;
+
+fn string_to_intcode (string: &str) -> Vec<i64> {
+    string.bytes().map(|byte| byte as i64).collect::<Vec<i64>>()
+}
+
+fn intcode_to_string (output: &[i64]) -> String {
+    output.iter().map(|&byte| byte as u8 as char).collect::<String>()
+}
+
+// with `verbose`, shows what the springdroid reported on stderr
+pub fn star_20_1 (tape: &[i64], verbose: bool) -> Option<i64> {
+    let input = string_to_intcode("NOT T T
+AND A T
+AND B T
+AND C T
+NOT T J
+AND D J
+WALK
+");
+    let output = intcode::run(tape, &input).expect("intcode program failed");
+    let last = output[output.len() - 1];
+
+    if verbose {
+        eprintln!("{}", intcode_to_string(&output));
+    }
+
+    if last < 256 {
+        None
+    } else {
+        Some(last)
+    }
+}
+
+pub fn star_20_2 (tape: &[i64], verbose: bool) -> Option<i64> {
+    let input = string_to_intcode("NOT T J
+AND A J
+AND B J
+AND C J
+NOT J J
+AND D J
+OR I T
+OR F T
+AND E T
+OR H T
+AND T J
+RUN
+");
+    let output = intcode::run(tape, &input).expect("intcode program failed");
+    let last = output[output.len() - 1];
+
+    if verbose {
+        eprintln!("{}", intcode_to_string(&output));
+    }
+
+    if last < 128 {
+        None
+    } else {
+        Some(last)
+    }
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (tape: &Vec<i64>) -> i64 {
+        star_20_1(tape, false).expect("springdroid fell")
+    }
+
+    fn part2 (tape: &Vec<i64>) -> i64 {
+        star_20_2(tape, false).expect("springdroid fell")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_ascii () {
+        assert_eq!(string_to_intcode("WALK\n"), vec![87, 65, 76, 75, 10]);
+        assert_eq!(intcode_to_string(&[87, 65, 76, 75, 10]), "WALK\n");
+    }
+
+    #[test]
+    fn hull_damage_is_the_last_output () {
+        // the droid made it across and reported a non-ASCII value
+        assert_eq!(star_20_1(&[104, 10, 104, 19354928, 99], false), Some(19354928));
+        assert_eq!(star_20_2(&[104, 10, 104, 1141814714, 99], false), Some(1141814714));
+    }
+
+    #[test]
+    fn falling_droid_gives_no_answer () {
+        // only the ASCII picture of the droid falling into space
+        let picture = "Didn't make it across:\n\n.................\n.................\n@................\n#####.#..########\n";
+        let mut tape = intcode::assembler::outputs(&string_to_intcode(picture));
+        tape.push(99);
+        assert_eq!(star_20_1(&tape, false), None);
+        assert_eq!(star_20_2(&tape, false), None);
+    }
+}
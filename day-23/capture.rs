@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::fmt;
+use std::fs;
+
+use super::{Computer, Packet, PacketContent};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Source {
+    Computer(i64),
+    Nat
+}
+
+// A packet and the event-loop tick it was sent in, counting from 1. Packets
+// sent in tick `t`, including wake-ups after it, are delivered before tick
+// `t + 1`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Captured {
+    pub tick: usize,
+    pub source: Source,
+    pub packet: Packet
+}
+
+// `tick source receiver x y`, with `nat` as the source of wake-up packets
+impl fmt::Display for Captured {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.source {
+            Source::Computer(address) => write!(f, "{} {}", self.tick, address)?,
+            Source::Nat => write!(f, "{} nat", self.tick)?
+        }
+        let PacketContent (x, y) = self.packet.content;
+        write!(f, " {} {} {}", self.packet.reciever, x, y)
+    }
+}
+
+fn parse_line (line: &str) -> Result<Captured, String> {
+    let fields = line.split_whitespace().collect::<Vec<&str>>();
+    if fields.len() != 5 {
+        return Err(format!("expected 5 fields, found {}", fields.len()))
+    }
+    let number = |field: &str| field.parse::<i64>().map_err(|_| format!("invalid number {}", field));
+
+    // ticks count from 1
+    let tick = match fields[0].parse::<usize>() {
+        Ok(tick) if tick > 0 => tick,
+        _ => return Err(format!("invalid tick {}", fields[0]))
+    };
+
+    Ok(Captured {
+        tick,
+        source: match fields[1] {
+            "nat" => Source::Nat,
+            address => Source::Computer(number(address)?)
+        },
+        packet: Packet {
+            reciever: number(fields[2])?,
+            content: PacketContent (number(fields[3])?, number(fields[4])?)
+        }
+    })
+}
+
+#[derive(Default)]
+pub struct Capture {
+    pub packets: Vec<Captured>
+}
+
+impl Capture {
+    pub fn record (&mut self, tick: usize, source: Source, packet: Packet) {
+        self.packets.push(Captured { tick, source, packet });
+    }
+
+    pub fn save (&self, path: &str) {
+        let lines = self.packets.iter().map(|packet| format!("{}\n", packet)).collect::<String>();
+        fs::write(path, lines).expect("unable to write capture");
+    }
+
+    pub fn load (path: &str) -> Result<Self, String> {
+        let file = fs::read_to_string(path).map_err(|error| format!("unable to read {}: {}", path, error))?;
+        Capture::parse(&file)
+    }
+
+    // fails with the number of the first invalid line
+    pub fn parse (file: &str) -> Result<Self, String> {
+        let mut packets = Vec::new();
+        for (i, line) in file.lines().enumerate() {
+            if !line.trim().is_empty() {
+                packets.push(parse_line(line).map_err(|error| format!("line {}: {}", i + 1, error))?);
+            }
+        }
+        Ok(Capture { packets })
+    }
+
+    // packets sent and received per computer, then every packet the NAT
+    // received and every wake-up it sent
+    pub fn view (&self) -> String {
+        let mut traffic: BTreeMap<i64, (usize, usize)> = BTreeMap::new();
+        let mut nat = Vec::new();
+
+        for captured in &self.packets {
+            if let Source::Computer(address) = captured.source {
+                traffic.entry(address).or_insert((0, 0)).0 += 1;
+            }
+            if captured.packet.reciever != 255 {
+                traffic.entry(captured.packet.reciever).or_insert((0, 0)).1 += 1;
+            }
+            if captured.packet.reciever == 255 || captured.source == Source::Nat {
+                nat.push(captured);
+            }
+        }
+
+        let mut view = String::from("computer  sent  received\n");
+        for (address, (sent, received)) in traffic {
+            view.push_str(&format!("{:>8}  {:>4}  {:>8}\n", address, sent, received));
+        }
+
+        view.push_str("\nnat\n");
+        for captured in nat {
+            let PacketContent (x, y) = captured.packet.content;
+            match captured.source {
+                Source::Nat => view.push_str(&format!("tick {:>5}: wake up 0 with ({}, {})\n", captured.tick, x, y)),
+                Source::Computer(address) => view.push_str(&format!("tick {:>5}: from {} ({}, {})\n", captured.tick, address, x, y))
+            }
+        }
+        view
+    }
+}
+
+// Runs a single computer through the ticks of a capture, delivering the
+// packets it received there, and compares what it sends with what the
+// capture says it sent. Returns the differences, or a summary if none, and
+// fails if the computer leaves a packet unfinished.
+pub fn replay (tape: &[i64], capture: &Capture, address: i64) -> Result<String, String> {
+    let mut computer = Computer {
+        address,
+        memory: intcode::ProgramState::new(tape),
+        ..Default::default()
+    };
+    computer.init();
+
+    let last_tick = capture.packets.iter().map(|captured| captured.tick).max().unwrap_or(0);
+    let packets_in_tick = |tick: usize, wanted: &dyn Fn(&Captured) -> bool| {
+        capture.packets.iter().filter(|captured| captured.tick == tick && wanted(captured)).map(|captured| captured.packet).collect::<Vec<Packet>>()
+    };
+
+    let mut report = String::new();
+    let mut sent = 0;
+    for tick in 1..=last_tick {
+        for packet in packets_in_tick(tick - 1, &|captured| captured.packet.reciever == address) {
+            computer.recieve_packet(&packet.content);
+        }
+
+        let output = computer.run_input_event();
+        let mut replayed = Vec::new();
+        for chunk in output.chunks(3) {
+            let chunk: [i64; 3] = chunk.try_into().map_err(|_| format!("tick {}: computer {} sent an incomplete packet {:?}", tick, address, chunk))?;
+            replayed.push(Packet::from(chunk));
+        }
+        let expected = packets_in_tick(tick, &|captured| captured.source == Source::Computer(address));
+
+        if replayed != expected {
+            report.push_str(&format!("tick {}: expected {:?}, sent {:?}\n", tick, expected, replayed));
+        }
+        sent += replayed.len();
+    }
+
+    if report.is_empty() {
+        report = format!("computer {}: {} packets over {} ticks, all as captured\n", address, sent, last_tick);
+    }
+    Ok(report)
+}
@@ -0,0 +1,296 @@
+use std::convert::TryInto;
+
+use solver::Solver;
+
+// one thread per computer, run with --threaded
+pub mod threaded;
+// packet logs, see --capture, --view and --replay
+pub mod capture;
+
+use capture::{Capture, Source};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PacketContent (i64, i64);
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Packet {
+    reciever: i64,
+    content: PacketContent
+}
+
+impl From<[i64; 3]> for Packet {
+    fn from (chunk: [i64; 3]) -> Self {
+        Packet {
+            reciever: chunk[0],
+            content: PacketContent (chunk[1], chunk[2])
+        }
+    }
+}
+
+// Feeds at most `budget` queued values per event, or a single -1 when the
+// queue is empty, and then pauses the computer until the next event.
+#[derive(Default)]
+struct Nic {
+    queue: Vec<i64>,
+    budget: usize,
+    output: Vec<i64>
+}
+
+impl intcode::io::Input for Nic {
+    fn read (&mut self) -> Option<i64> {
+        if self.budget == 0 {
+            return None
+        }
+        self.budget -= 1;
+        Some(self.queue.remove(0))
+    }
+}
+
+impl intcode::io::Output for Nic {
+    fn write (&mut self, value: i64) {
+        self.output.push(value);
+    }
+}
+
+#[derive(Default)]
+struct Computer {
+    address: i64,
+    memory: intcode::ProgramState,
+    nic: Nic
+}
+
+impl Computer {
+    fn init (&mut self) {
+        self.nic.queue.push(self.address);
+    }
+
+    fn recieve_packet (&mut self, packet: &PacketContent) {
+        self.nic.queue.push(packet.0);
+        self.nic.queue.push(packet.1);
+    }
+
+    fn run_input_event (&mut self) -> Vec<i64> {
+        if self.nic.queue.is_empty() {
+            self.nic.queue.push(-1)
+        }
+
+        self.nic.budget = std::cmp::min(self.nic.queue.len(), 2);
+        intcode::io::run_device(&mut self.memory, &mut self.nic).expect("intcode program failed");
+        self.nic.output.drain(..).collect()
+    }
+}
+
+pub struct Network {
+    computers: Vec<Computer>,
+    last_nat_packet: Option<Packet>,
+    all_idle: bool,
+    tick: usize,
+    pub capture: Option<Capture>
+}
+
+impl Network {
+    fn init (&mut self) {
+        for computer in &mut self.computers {
+            computer.init();
+        }
+    }
+
+    pub fn new (tape: &[i64], size: i64) -> Self {
+        let computers = (0..size).map(|address| Computer {
+            address,
+            memory: intcode::ProgramState {
+                tape: tape.to_vec(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }).collect::<Vec<Computer>>();
+
+        Network {
+            computers,
+            last_nat_packet: None,
+            all_idle: false,
+            tick: 0,
+            capture: None
+        }
+    }
+
+    fn send_packet (&mut self, source: Source, packet: Packet) {
+        if let Some(capture) = &mut self.capture {
+            capture.record(self.tick, source, packet);
+        }
+        let computer = &mut self.computers[packet.reciever as usize];
+        computer.recieve_packet(&packet.content);
+    }
+
+    fn run_event_loop (&mut self) {
+        self.tick += 1;
+        let mut all_packets: Vec<(i64, Packet)> = Vec::new();
+        let mut all_idle = true;
+
+        for computer in &mut self.computers {
+            if !computer.nic.queue.is_empty() { all_idle = false }
+
+            let output = computer.run_input_event();
+            let address = computer.address;
+            let mut packets: Vec<(i64, Packet)> = output
+                .chunks(3)
+                .map(|chunk| {
+                    let sized_chunk: [i64; 3] = chunk.try_into().expect("unexpected remainder");
+                    (address, Packet::from(sized_chunk))
+                })
+                .collect::<Vec<(i64, Packet)>>();
+
+            if !computer.nic.queue.is_empty() { all_idle = false }
+            all_packets.append(&mut packets);
+        }
+
+        if all_idle && all_packets.is_empty() {
+            self.all_idle = true
+        }
+
+        for (address, packet) in all_packets {
+            if packet.reciever == 255 {
+                if let Some(capture) = &mut self.capture {
+                    capture.record(self.tick, Source::Computer(address), packet);
+                }
+                self.last_nat_packet = Some(packet);
+            } else {
+                self.send_packet(Source::Computer(address), packet);
+            }
+        }
+    }
+}
+
+pub fn star_23_1 (tape: &[i64]) -> i64 {
+    let mut network = Network::new(tape, 50);
+    network.init();
+
+    loop {
+        network.run_event_loop();
+        if let Some(packet) = network.last_nat_packet {
+            return packet.content.1;
+        }
+    }
+}
+
+pub fn star_23_2 (tape: &[i64]) -> i64 {
+    let mut network = Network::new(tape, 50);
+    run_with_nat(&mut network)
+}
+
+pub fn run_with_nat (network: &mut Network) -> i64 {
+    network.init();
+
+    let mut wakeup_calls = std::collections::HashSet::new();
+    loop {
+        network.run_event_loop();
+
+        if network.all_idle {
+            let packet = &network.last_nat_packet.unwrap();
+            network.last_nat_packet = None;
+            if !wakeup_calls.insert(packet.content.1) {
+                return packet.content.1
+            }
+            network.all_idle = false;
+            network.send_packet(Source::Nat, Packet {
+                reciever: 0,
+                content: packet.content
+            });
+        }
+    }
+}
+
+fn parse_number (string: &str) -> i64 {
+	string.parse::<i64>().expect("cannot parse string as int")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse (input: &str) -> Vec<i64> {
+        input.trim().split(',').map(parse_number).collect()
+    }
+
+    fn part1 (tape: &Vec<i64>) -> i64 {
+        star_23_1(tape)
+    }
+
+    fn part2 (tape: &Vec<i64>) -> i64 {
+        star_23_2(tape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every computer sends (1, 42) to the NAT at boot, and then passes every
+    // packet it receives on to the NAT.
+    static FORWARD: [i64; 32] = [
+        3, 28,
+        104, 255, 104, 1, 104, 42,
+        3, 29,
+        1008, 29, -1, 31,
+        1005, 31, 8,
+        3, 30,
+        104, 255, 4, 29, 4, 30,
+        1105, 1, 8,
+        0, 0, 0, 0
+    ];
+
+    #[test]
+    fn first_packet_to_nat () {
+        assert_eq!(star_23_1(&FORWARD), 42);
+    }
+
+    #[test]
+    fn first_wake_up_sent_twice () {
+        assert_eq!(star_23_2(&FORWARD), 42);
+        assert_eq!(threaded::run_network(&FORWARD, 50, true), Ok(42));
+    }
+
+    #[test]
+    fn replays_captures () {
+        let mut network = Network::new(&FORWARD, 3);
+        network.capture = Some(Capture::default());
+        run_with_nat(&mut network);
+        let capture = network.capture.unwrap();
+        assert_eq!(capture::replay(&FORWARD, &capture, 1).unwrap(), "computer 1: 1 packets over 3 ticks, all as captured\n");
+    }
+
+    #[test]
+    fn replay_rejects_incomplete_packets () {
+        // sends half a packet and waits
+        let tape = [3, 100, 104, 255, 104, 1, 3, 100, 99];
+        let capture = Capture { packets: vec![capture::Captured { tick: 1, source: Source::Computer(0), packet: Packet::from([255, 1, 2]) }] };
+        assert_eq!(capture::replay(&tape, &capture, 0), Err(String::from("tick 1: computer 0 sent an incomplete packet [255, 1]")));
+    }
+
+    #[test]
+    fn loads_captures () {
+        let capture = Capture::parse("1 0 255 1 42\n\n2 nat 0 1 42\n").unwrap();
+        assert_eq!(capture.packets[1], capture::Captured { tick: 2, source: Source::Nat, packet: Packet::from([0, 1, 42]) });
+        assert_eq!(Capture::parse("1 0 255 1 42\n-1 0 255 1 42\n").err(), Some(String::from("line 2: invalid tick -1")));
+        assert_eq!(Capture::parse("1 0 255 1").err(), Some(String::from("line 1: expected 5 fields, found 4")));
+        assert_eq!(Capture::parse("1 x 255 1 42").err(), Some(String::from("line 1: invalid number x")));
+        assert!(Capture::load("no such capture").err().unwrap().starts_with("unable to read no such capture"));
+    }
+
+    #[test]
+    fn threaded_failures () {
+        let error = threaded::run_network(&[42], 2, false).unwrap_err();
+        assert!(matches!(error, threaded::NetworkError::Computer(_, intcode::IntcodeError { kind: intcode::ErrorKind::UnknownOpcode, .. })));
+        assert_eq!(threaded::run_network(&[99], 2, false), Err(threaded::NetworkError::Stopped));
+        // computer 0 sends to -1, then waits for input
+        let tape = [3, 100, 1008, 100, 0, 100, 1006, 100, 15, 104, -1, 104, 1, 104, 2, 3, 100, 1105, 1, 15];
+        assert_eq!(threaded::run_network(&tape, 2, false), Err(threaded::NetworkError::UnknownAddress(0, -1)));
+    }
+
+    #[test]
+    fn reads_packets () {
+        assert_eq!(Packet::from([255, 1, 42]), Packet { reciever: 255, content: PacketContent (1, 42) });
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::{HashSet, VecDeque};
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use intcode::io::{Input, Output, Status};
+use intcode::IntcodeError;
+
+use super::{Packet, PacketContent};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetworkError {
+    // the program of the computer at this address failed
+    Computer(usize, IntcodeError),
+    // a computer sent a packet to an address that isn't on the network
+    UnknownAddress(usize, i64),
+    Panicked(usize),
+    // a computer halted, so the network can never be idle
+    Stopped,
+    Disconnected
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::Computer(address, error) => write!(f, "computer {} failed: {}", address, error),
+            NetworkError::UnknownAddress(address, reciever) => write!(f, "computer {} sent a packet to unknown address {}", address, reciever),
+            NetworkError::Panicked(address) => write!(f, "computer {} panicked", address),
+            NetworkError::Stopped => write!(f, "a computer halted"),
+            NetworkError::Disconnected => write!(f, "the NAT was disconnected")
+        }
+    }
+}
+
+impl error::Error for NetworkError {}
+
+// How often a waiting computer (and the NAT) checks whether the network is
+// stopping.
+const IDLE_WAIT: Duration = Duration::from_millis(1);
+
+// What the NAT needs to decide that the network is idle. Every packet is
+// counted from when it is sent until its receiver has taken it, and a
+// computer is idle only while its NIC is blocked waiting for a packet, with
+// nothing pending, so it cannot send anything. A computer that has read -1
+// twice without sending anything in between is taken to be polling, and its
+// NIC blocks on its next read instead of returning -1 again. That assumes,
+// as the puzzle does, that reading more -1s would not make it send. The
+// `activity` changes with every send and receive, so the NAT can tell
+// whether its look at the network was taken at a single moment.
+struct Monitor {
+    idle: Vec<AtomicBool>,
+    in_flight: AtomicUsize,
+    activity: AtomicUsize,
+    stop: AtomicBool
+}
+
+impl Monitor {
+    fn sending (&self) {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        self.activity.fetch_add(1, Ordering::SeqCst);
+    }
+
+    // the receiver is marked busy before the packet stops counting
+    fn received (&self, address: Option<usize>) {
+        if let Some(address) = address {
+            self.idle[address].store(false, Ordering::SeqCst);
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        self.activity.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn quiescent (&self) -> bool {
+        let activity = self.activity.load(Ordering::SeqCst);
+        self.in_flight.load(Ordering::SeqCst) == 0
+            && self.idle.iter().all(|idle| idle.load(Ordering::SeqCst))
+            && self.activity.load(Ordering::SeqCst) == activity
+    }
+}
+
+struct Nic {
+    address: usize,
+    pending: VecDeque<i64>,
+    inbox: Receiver<PacketContent>,
+    computers: Vec<Sender<PacketContent>>,
+    nat: Sender<PacketContent>,
+    monitor: Arc<Monitor>,
+    output: Vec<i64>,
+    empty_reads: usize,
+    // pause after every -1, so the computer's thread can notice `stop`
+    paused: bool,
+    // the address of a packet that could not be delivered
+    unknown_address: Option<i64>
+}
+
+impl Nic {
+    // Blocks, marked idle, until a packet arrives or the network stops. The
+    // sender marks the computer busy again before the packet stops counting.
+    fn wait (&mut self) -> Option<PacketContent> {
+        self.monitor.idle[self.address].store(true, Ordering::SeqCst);
+        loop {
+            match self.inbox.recv_timeout(IDLE_WAIT) {
+                Ok(packet) => return Some(packet),
+                Err(RecvTimeoutError::Timeout) if !self.monitor.stop.load(Ordering::SeqCst) => {},
+                Err(_) => return None
+            }
+        }
+    }
+}
+
+impl Input for Nic {
+    fn read (&mut self) -> Option<i64> {
+        if let Some(value) = self.pending.pop_front() {
+            return Some(value)
+        }
+        if self.paused || self.unknown_address.is_some() {
+            self.paused = false;
+            return None
+        }
+
+        let packet = if self.empty_reads >= 2 {
+            Some(self.wait()?)
+        } else {
+            self.inbox.try_recv().ok()
+        };
+
+        match packet {
+            Some(PacketContent (x, y)) => {
+                self.empty_reads = 0;
+                self.monitor.received(Some(self.address));
+                self.pending.push_back(y);
+                Some(x)
+            },
+            None => {
+                self.empty_reads += 1;
+                self.paused = true;
+                Some(-1)
+            }
+        }
+    }
+}
+
+impl Output for Nic {
+    fn write (&mut self, value: i64) {
+        self.empty_reads = 0;
+        self.output.push(value);
+        if self.output.len() < 3 {
+            return
+        }
+
+        let packet = Packet { reciever: self.output[0], content: PacketContent (self.output[1], self.output[2]) };
+        self.output.clear();
+        let channel = match packet.reciever {
+            255 => &self.nat,
+            reciever => match usize::try_from(reciever).ok().and_then(|reciever| self.computers.get(reciever)) {
+                Some(channel) => channel,
+                None => {
+                    // reported once the computer stops for input or halts
+                    self.unknown_address.get_or_insert(reciever);
+                    return
+                }
+            }
+        };
+        self.monitor.sending();
+        // a send only fails once the network is shutting down
+        let _ = channel.send(packet.content);
+    }
+}
+
+// Stops the whole network when a computer's thread ends, however it ends.
+struct StopOnExit (Arc<Monitor>);
+
+impl Drop for StopOnExit {
+    fn drop (&mut self) {
+        self.0.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+fn run_computer (tape: Vec<i64>, mut nic: Nic) -> Result<(), NetworkError> {
+    let _stop = StopOnExit(Arc::clone(&nic.monitor));
+    let mut state = intcode::ProgramState::new(&tape);
+    nic.pending.push_back(nic.address as i64);
+
+    while !nic.monitor.stop.load(Ordering::SeqCst) {
+        let status = intcode::io::run_device(&mut state, &mut nic).map_err(|error| NetworkError::Computer(nic.address, error))?;
+        if let Some(reciever) = nic.unknown_address {
+            return Err(NetworkError::UnknownAddress(nic.address, reciever))
+        }
+        if status == Status::Halted {
+            break
+        }
+    }
+    Ok(())
+}
+
+// One thread per computer, with the NAT on the calling thread. With
+// `wake_up` false, returns the Y value of the first packet sent to the NAT;
+// otherwise the first Y value the NAT sends to computer 0 for a second time.
+// Fails if any computer fails or stops before that.
+pub fn run_network (tape: &[i64], size: usize, wake_up: bool) -> Result<i64, NetworkError> {
+    let monitor = Arc::new(Monitor {
+        idle: (0..size).map(|_| AtomicBool::new(false)).collect(),
+        in_flight: AtomicUsize::new(0),
+        activity: AtomicUsize::new(0),
+        stop: AtomicBool::new(false)
+    });
+    let (nat, nat_inbox) = mpsc::channel();
+    let (computers, inboxes): (Vec<Sender<PacketContent>>, Vec<Receiver<PacketContent>>) = (0..size).map(|_| mpsc::channel()).unzip();
+
+    let threads = inboxes.into_iter().enumerate().map(|(address, inbox)| {
+        let nic = Nic {
+            address,
+            pending: VecDeque::new(),
+            inbox,
+            computers: computers.clone(),
+            nat: nat.clone(),
+            monitor: Arc::clone(&monitor),
+            output: Vec::new(),
+            empty_reads: 0,
+            paused: false,
+            unknown_address: None
+        };
+        let tape = tape.to_vec();
+        thread::spawn(move || run_computer(tape, nic))
+    }).collect::<Vec<thread::JoinHandle<Result<(), NetworkError>>>>();
+
+    let mut last_nat_packet = None;
+    let mut wakeup_calls = HashSet::new();
+    let answer = loop {
+        if monitor.stop.load(Ordering::SeqCst) {
+            break Err(NetworkError::Stopped)
+        }
+
+        match nat_inbox.recv_timeout(IDLE_WAIT) {
+            Ok(content) => {
+                monitor.received(None);
+                if !wake_up {
+                    break Ok(content.1)
+                }
+                last_nat_packet = Some(content);
+                continue
+            },
+            Err(RecvTimeoutError::Timeout) => {},
+            Err(RecvTimeoutError::Disconnected) => break Err(NetworkError::Disconnected)
+        }
+
+        if let (true, Some(content)) = (monitor.quiescent(), last_nat_packet) {
+            if !wakeup_calls.insert(content.1) {
+                break Ok(content.1)
+            }
+            monitor.sending();
+            if computers[0].send(content).is_err() {
+                break Err(NetworkError::Stopped)
+            }
+        }
+    };
+
+    // what went wrong in a computer says more than how the NAT noticed
+    monitor.stop.store(true, Ordering::SeqCst);
+    let mut failure = None;
+    for (address, thread) in threads.into_iter().enumerate() {
+        let error = match thread.join() {
+            Ok(Ok(())) => continue,
+            Ok(Err(error)) => error,
+            Err(_) => NetworkError::Panicked(address)
+        };
+        failure.get_or_insert(error);
+    }
+    match failure {
+        Some(error) => Err(error),
+        None => answer
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use intcode::memory::GROWTH_LIMIT;
+use intcode::opcode;
+use intcode::{ErrorKind, IntcodeError};
+
+fn convert_pointer (pointer: i64) -> Result<usize, ErrorKind> {
+    if pointer >= 0 {
+        Ok(pointer as usize)
+    } else {
+        Err(ErrorKind::NegativePointer(pointer))
+    }
+}
+
+fn get_value (state: &ProgramState, pointer: i64, mode: i64) -> Result<i64, ErrorKind> {
+    let immediate_value = state.read(convert_pointer(pointer)?);
+    match mode {
+        0 => get_value(state, immediate_value, 1),
+        1 => Ok(immediate_value),
+        2 => get_value(state, immediate_value + state.relative_base, 1),
+        _ => Err(ErrorKind::UnknownMode(mode))
+    }
+}
+
+fn get_pointer (state: &ProgramState, pointer: i64, mode: i64) -> Result<usize, ErrorKind> {
+    let pointer_value = get_value(state, pointer, 1)?;
+    let absolute_pointer = match mode {
+        0 | 1 => pointer_value,
+        2 => pointer_value + state.relative_base,
+        _ => return Err(ErrorKind::UnknownMode(mode))
+    };
+    convert_pointer(absolute_pointer)
+}
+
+fn get_mode (modes: i64, i: i64) -> i64 {
+    (modes / 10_i64.pow(i as u32)) % 10
+}
+
+fn get_parameters (state: &ProgramState, pointer: i64, number: (i64, i64), modes: i64) -> Result<(Vec<i64>, Vec<usize>), ErrorKind> {
+    let mut values = (Vec::new(), Vec::new());
+
+    for i in 0..number.0 {
+        values.0.push(get_value(state, pointer + i, get_mode(modes, i))?);
+    }
+
+    for i in number.0..(number.0 + number.1) {
+        values.1.push(get_pointer(state, pointer + i, get_mode(modes, i))?);
+    }
+
+    Ok(values)
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct ProgramState {
+    pub tape: Vec<i64>,
+    pub input: Vec<i64>,
+    pub tape_index: i64,
+    pub input_index: usize,
+    pub relative_base: i64,
+    pub sparse: HashMap<usize, i64>
+}
+
+impl ProgramState {
+    pub fn read (&self, address: usize) -> i64 {
+        match self.tape.get(address) {
+            Some(&value) => value,
+            None => self.sparse.get(&address).copied().unwrap_or(0)
+        }
+    }
+
+    pub fn write (&mut self, address: usize, value: i64) {
+        if address >= self.tape.len() {
+            if address - self.tape.len() >= GROWTH_LIMIT {
+                self.sparse.insert(address, value);
+                return
+            }
+
+            self.tape.resize(address + 1, 0);
+            if !self.sparse.is_empty() {
+                let below = self.sparse.keys().filter(|&&sparse| sparse <= address).copied().collect::<Vec<usize>>();
+                for sparse in below {
+                    self.tape[sparse] = self.sparse.remove(&sparse).unwrap();
+                }
+            }
+        }
+
+        self.tape[address] = value;
+    }
+
+    pub fn next_opcode (&self) -> Option<i64> {
+        let index = convert_pointer(self.tape_index).ok()?;
+        Some(self.read(index) % 100)
+    }
+
+    pub fn is_done (&self) -> bool {
+        self.next_opcode() == Some(99)
+    }
+}
+
+pub fn do_instruction (state: &mut ProgramState) -> Result<Option<i64>, IntcodeError> {
+    let tape_index = state.tape_index;
+    let fault = |opcode, kind| IntcodeError { tape_index, opcode, kind };
+
+    let instruction = get_value(state, tape_index, 1).map_err(|kind| fault(None, kind))?;
+    let opcode = instruction % 100;
+    let modes = instruction / 100;
+    let fault = |kind| fault(Some(opcode), kind);
+
+    let parameters = opcode::parameters(opcode).ok_or_else(|| fault(ErrorKind::UnknownOpcode))?;
+
+    if opcode == 3 && state.input_index >= state.input.len() {
+        return Err(fault(ErrorKind::MissingInput))
+    }
+
+    let (values, pointers) = get_parameters(state, tape_index + 1, parameters, modes).map_err(fault)?;
+    state.tape_index = tape_index + 1 + parameters.0 + parameters.1;
+
+    let mut output = None;
+    let mut write = None;
+    match opcode {
+        1 => {
+            write = Some(values[0] + values[1])
+        },
+        2 => {
+            write = Some(values[0] * values[1])
+        },
+        3 => {
+            write = Some(state.input[state.input_index]);
+            state.input_index += 1
+        },
+        4 => {
+            output = Some(values[0])
+        },
+        5 => {
+            if values[0] != 0 {
+                state.tape_index = values[1]
+            }
+        },
+        6 => {
+            if values[0] == 0 {
+                state.tape_index = values[1]
+            }
+        },
+        7 => {
+            write = Some((values[0] < values[1]) as i64)
+        },
+        8 => {
+            write = Some((values[0] == values[1]) as i64)
+        },
+        9 => {
+            state.relative_base += values[0]
+        },
+        99 => {},
+        _ => unreachable!()
+    }
+
+    if let Some(value) = write {
+        state.write(pointers[0], value);
+    }
+
+    Ok(output)
+}
+
+pub fn step (state: &mut ProgramState) -> Result<Option<i64>, IntcodeError> {
+    let mut output = None;
+    while output.is_none() && !state.is_done() {
+        output = do_instruction(state)?;
+    }
+    Ok(output)
+}
+
+pub fn run (intcode: &[i64], input: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+    let mut output = Vec::new();
+    let mut state = ProgramState {
+        tape: intcode.to_vec(),
+        input: input.to_vec(),
+        ..Default::default()
+    };
+
+    while let Some(step_output) = step(&mut state)? {
+        output.push(step_output)
+    }
+
+    Ok(output)
+}
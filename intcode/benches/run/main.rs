@@ -0,0 +1,81 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use intcode::assembler::assemble;
+
+// The interpreter as it was just before instructions were pre-decoded
+// (sparse memory, typed errors), without the tracer, to compare against.
+mod legacy;
+
+// multiplies x and y by repeated addition, like a day-19 beam check
+const MULTIPLY: &str = "
+        in   x
+        in   y
+loop:   jf   y, #done
+        add  acc, x, acc
+        add  y, #-1, y
+        jt   #1, #loop
+done:   lt   acc, #1000, result
+        out  result
+        hlt
+x:      db   0
+y:      db   0
+acc:    db   0
+result: db   0
+";
+
+// counts to a million on the relative-base stack
+const COUNT: &str = "
+        arb  #stack
+loop:   add  rb+0, #1, rb+0
+        lt   rb+0, #1000000, rb+1
+        jt   rb+1, #loop
+        out  rb+0
+        hlt
+stack:  ds   2
+";
+
+fn time<F: FnMut()> (mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..5 {
+        f();
+    }
+    start.elapsed() / 5
+}
+
+fn compare (name: &str, legacy: Duration, current: Duration) {
+    println!(
+        "{: <24} legacy {: >10.2?}  current {: >10.2?}  speedup {:.2}x",
+        name,
+        legacy,
+        current,
+        legacy.as_secs_f64() / current.as_secs_f64()
+    );
+}
+
+fn grid<F: Fn(&[i64], &[i64]) -> Result<Vec<i64>, intcode::IntcodeError>> (run: F, tape: &[i64]) {
+    for x in 0..50 {
+        for y in 0..50 {
+            black_box(run(tape, &[x, y]).unwrap());
+        }
+    }
+}
+
+fn main () {
+    let multiply = assemble(MULTIPLY).expect("invalid benchmark program");
+    let count = assemble(COUNT).expect("invalid benchmark program");
+
+    assert_eq!(legacy::run(&count, &[]).unwrap(), intcode::run(&count, &[]).unwrap());
+
+    compare(
+        "2500 short runs",
+        time(|| grid(legacy::run, &multiply)),
+        time(|| grid(intcode::run, &multiply))
+    );
+
+    compare(
+        "one long run",
+        time(|| { black_box(legacy::run(&count, &[]).unwrap()); }),
+        time(|| { black_box(intcode::run(&count, &[]).unwrap()); })
+    );
+}
@@ -0,0 +1,70 @@
+use crate::ErrorKind;
+
+// What `add` and `mul` (and `arb`) do when the result does not fit in an
+// i64. Plain `+` and `*` would panic in debug builds and wrap in release
+// builds; both policies behave the same in every build. Unbounded integers
+// are a separate machine with its own cells and outputs, see `bigint`
+// (behind the `bigint` feature).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Arithmetic {
+    // fail with `ErrorKind::Overflow`
+    #[default]
+    Checked,
+    Wrapping
+}
+
+impl Arithmetic {
+    pub fn add (self, a: i64, b: i64) -> Result<i64, ErrorKind> {
+        match self {
+            Arithmetic::Checked => a.checked_add(b).ok_or(ErrorKind::Overflow),
+            Arithmetic::Wrapping => Ok(a.wrapping_add(b))
+        }
+    }
+
+    pub fn mul (self, a: i64, b: i64) -> Result<i64, ErrorKind> {
+        match self {
+            Arithmetic::Checked => a.checked_mul(b).ok_or(ErrorKind::Overflow),
+            Arithmetic::Wrapping => Ok(a.wrapping_mul(b))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{do_instruction, ProgramState};
+
+    #[test]
+    fn policies () {
+        assert_eq!(Arithmetic::Checked.add(i64::MAX, 1), Err(ErrorKind::Overflow));
+        assert_eq!(Arithmetic::Checked.mul(i64::MIN, -1), Err(ErrorKind::Overflow));
+        assert_eq!(Arithmetic::Checked.add(2, 3), Ok(5));
+        assert_eq!(Arithmetic::Wrapping.add(i64::MAX, 1), Ok(i64::MIN));
+        assert_eq!(Arithmetic::Wrapping.mul(i64::MAX, 2), Ok(-2));
+    }
+
+    #[test]
+    fn overflow_changes_nothing () {
+        let tape = [1102, i64::MAX, 2, 5, 99, 0];
+        let mut state = ProgramState::new(&tape);
+        let error = do_instruction(&mut state).unwrap_err();
+        assert_eq!((error.tape_index, error.kind), (0, ErrorKind::Overflow));
+        assert_eq!(state.tape_index, 0);
+        assert_eq!(state.tape, tape.to_vec());
+
+        state.arithmetic = Arithmetic::Wrapping;
+        do_instruction(&mut state).unwrap();
+        assert_eq!(state.read(5), -2);
+    }
+
+    #[test]
+    fn relative_base () {
+        let mut state = ProgramState::new(&[109, 1, 99]);
+        state.relative_base = i64::MAX;
+        assert_eq!(do_instruction(&mut state).unwrap_err().kind, ErrorKind::Overflow);
+
+        state.arithmetic = Arithmetic::Wrapping;
+        do_instruction(&mut state).unwrap();
+        assert_eq!(state.relative_base, i64::MIN);
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::disassembler::Mode;
+use crate::opcode;
+
+// the most cells a single `ds` may reserve
+pub const RESERVE_LIMIT: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssemblyErrorKind {
+    UnknownMnemonic(String),
+    OperandCount { expected: usize, found: usize },
+    InvalidOperand(String),
+    ImmediateWrite(String),
+    InvalidLabel(String),
+    DuplicateLabel(String),
+    UnknownLabel(String),
+    AddressMismatch { expected: usize, found: usize },
+    UnterminatedString,
+    ReserveTooLarge(usize)
+}
+
+impl fmt::Display for AssemblyErrorKind {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssemblyErrorKind::UnknownMnemonic(mnemonic) => write!(f, "unknown mnemonic: {}", mnemonic),
+            AssemblyErrorKind::OperandCount { expected, found } => write!(f, "expected {} operands, found {}", expected, found),
+            AssemblyErrorKind::InvalidOperand(operand) => write!(f, "invalid operand: {}", operand),
+            AssemblyErrorKind::ImmediateWrite(operand) => write!(f, "cannot write to immediate operand: {}", operand),
+            AssemblyErrorKind::InvalidLabel(label) => write!(f, "invalid label: {}", label),
+            AssemblyErrorKind::DuplicateLabel(label) => write!(f, "duplicate label: {}", label),
+            AssemblyErrorKind::UnknownLabel(label) => write!(f, "unknown label: {}", label),
+            AssemblyErrorKind::AddressMismatch { expected, found } => write!(f, "expected address {}, found {}", expected, found),
+            AssemblyErrorKind::UnterminatedString => write!(f, "unterminated string"),
+            AssemblyErrorKind::ReserveTooLarge(size) => write!(f, "cannot reserve {} cells, at most {}", size, RESERVE_LIMIT)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyError {
+    pub line: usize,
+    pub kind: AssemblyErrorKind
+}
+
+impl fmt::Display for AssemblyError {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl error::Error for AssemblyError {}
+
+// a number or a label, resolved in the second pass
+#[derive(Debug)]
+enum Term {
+    Number(i64),
+    Label(String)
+}
+
+#[derive(Debug)]
+enum Item {
+    Instruction { opcode: i64, operands: Vec<(Mode, Term)> },
+    Data(Vec<Term>)
+}
+
+impl Item {
+    fn size (&self) -> usize {
+        match self {
+            Item::Instruction { operands, .. } => 1 + operands.len(),
+            Item::Data(values) => values.len()
+        }
+    }
+}
+
+fn is_label (string: &str) -> bool {
+    let mut chars = string.chars();
+    match chars.next() {
+        Some(char) if char.is_ascii_alphabetic() || char == '_' => {
+            chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+        },
+        _ => false
+    }
+}
+
+fn parse_term (string: &str) -> Result<Term, AssemblyErrorKind> {
+    if is_label(string) {
+        Ok(Term::Label(string.to_string()))
+    } else {
+        let number = string.strip_prefix('+').unwrap_or(string);
+        number.parse::<i64>().map(Term::Number).map_err(|_| AssemblyErrorKind::InvalidOperand(string.to_string()))
+    }
+}
+
+// `12` is positional, `#12` immediate and `rb+12` or `rb-12` relative
+fn parse_operand (string: &str) -> Result<(Mode, Term), AssemblyErrorKind> {
+    if let Some(rest) = string.strip_prefix('#') {
+        Ok((Mode::Immediate, parse_term(rest)?))
+    } else if let Some(rest) = string.strip_prefix("rb") {
+        match rest.chars().next() {
+            Some('+') => Ok((Mode::Relative, parse_term(&rest[1..])?)),
+            Some('-') => match parse_term(&rest[1..])? {
+                Term::Number(number) => match number.checked_neg() {
+                    Some(number) => Ok((Mode::Relative, Term::Number(number))),
+                    None => Err(AssemblyErrorKind::InvalidOperand(string.to_string()))
+                },
+                Term::Label(_) => Err(AssemblyErrorKind::InvalidOperand(string.to_string()))
+            },
+            _ => Ok((Mode::Position, parse_term(string)?))
+        }
+    } else {
+        Ok((Mode::Position, parse_term(string)?))
+    }
+}
+
+// splits on commas outside of string literals and removes the comment
+fn split_fields (string: &str) -> Result<Vec<String>, AssemblyErrorKind> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_string = false;
+
+    for char in string.chars() {
+        match char {
+            '"' => {
+                in_string = !in_string;
+                field.push(char);
+            },
+            ';' if !in_string => break,
+            ',' if !in_string => {
+                fields.push(field.trim().to_string());
+                field = String::new();
+            },
+            _ => field.push(char)
+        }
+    }
+
+    if in_string {
+        return Err(AssemblyErrorKind::UnterminatedString)
+    }
+
+    if !field.trim().is_empty() || !fields.is_empty() {
+        fields.push(field.trim().to_string());
+    }
+
+    Ok(fields)
+}
+
+fn parse_data (fields: &[String]) -> Result<Item, AssemblyErrorKind> {
+    let mut values = Vec::new();
+
+    for field in fields {
+        if field.len() >= 2 && field.starts_with('"') && field.ends_with('"') {
+            let text = field[1..(field.len() - 1)].replace("\\n", "\n");
+            values.extend(text.bytes().map(|byte| Term::Number(byte as i64)));
+        } else {
+            values.push(parse_term(field)?);
+        }
+    }
+
+    Ok(Item::Data(values))
+}
+
+fn parse_instruction (mnemonic: &str, fields: &[String]) -> Result<Item, AssemblyErrorKind> {
+    let opcode = opcode::from_mnemonic(mnemonic).ok_or_else(|| AssemblyErrorKind::UnknownMnemonic(mnemonic.to_string()))?;
+    let (values, pointers) = opcode::parameters(opcode).unwrap();
+    let expected = (values + pointers) as usize;
+
+    if fields.len() != expected {
+        return Err(AssemblyErrorKind::OperandCount { expected, found: fields.len() })
+    }
+
+    let mut operands = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let operand = parse_operand(field)?;
+        if i as i64 >= values && operand.0 == Mode::Immediate {
+            return Err(AssemblyErrorKind::ImmediateWrite(field.to_string()))
+        }
+        operands.push(operand);
+    }
+
+    Ok(Item::Instruction { opcode, operands })
+}
+
+// parses the part of a line after any label, returning `None` for empty lines
+fn parse_statement (statement: &str) -> Result<Option<Item>, AssemblyErrorKind> {
+    let statement = statement.trim_start();
+    let (mnemonic, rest) = match statement.find(char::is_whitespace) {
+        Some(index) => statement.split_at(index),
+        None => (statement, "")
+    };
+
+    if mnemonic.is_empty() || mnemonic.starts_with(';') {
+        return Ok(None)
+    }
+
+    let fields = split_fields(rest)?;
+    match mnemonic {
+        "db" => parse_data(&fields).map(Some),
+        "ds" => match fields.as_slice() {
+            [size] => match size.parse::<usize>() {
+                Ok(size) if size > RESERVE_LIMIT => Err(AssemblyErrorKind::ReserveTooLarge(size)),
+                Ok(size) => Ok(Some(Item::Data((0..size).map(|_| Term::Number(0)).collect()))),
+                Err(_) => Err(AssemblyErrorKind::InvalidOperand(size.to_string()))
+            },
+            _ => Err(AssemblyErrorKind::OperandCount { expected: 1, found: fields.len() })
+        },
+        _ => parse_instruction(mnemonic, &fields).map(Some)
+    }
+}
+
+fn resolve (term: &Term, labels: &HashMap<String, usize>) -> Result<i64, AssemblyErrorKind> {
+    match term {
+        Term::Number(number) => Ok(*number),
+        Term::Label(label) => labels
+            .get(label)
+            .map(|&address| address as i64)
+            .ok_or_else(|| AssemblyErrorKind::UnknownLabel(label.to_string()))
+    }
+}
+
+fn mode_digit (mode: Mode) -> i64 {
+    match mode {
+        Mode::Position => 0,
+        Mode::Immediate => 1,
+        Mode::Relative => 2
+    }
+}
+
+// Assembles the syntax printed by the disassembler. Lines look like
+//
+//     loop: add #1, counter, counter  ; comment
+//           jt  #1, #loop
+//     counter: db 0
+//
+// Labels may be used wherever a number is expected, `db` emits values or
+// strings, `ds n` reserves n zeroed cells and a numeric label such as `12:`
+// asserts the current address.
+pub fn assemble (source: &str) -> Result<Vec<i64>, AssemblyError> {
+    let mut items = Vec::new();
+    let mut labels = HashMap::new();
+    let mut address = 0;
+
+    for (i, line) in source.lines().enumerate() {
+        let fault = |kind| AssemblyError { line: i + 1, kind };
+        let mut statement = line;
+
+        while let Some(index) = statement.find(':') {
+            let label = statement[..index].trim();
+            if label.contains([';', '"']) {
+                break
+            }
+
+            if let Ok(expected) = label.parse::<usize>() {
+                if expected != address {
+                    return Err(fault(AssemblyErrorKind::AddressMismatch { expected, found: address }))
+                }
+            } else if !is_label(label) {
+                return Err(fault(AssemblyErrorKind::InvalidLabel(label.to_string())))
+            } else if labels.insert(label.to_string(), address).is_some() {
+                return Err(fault(AssemblyErrorKind::DuplicateLabel(label.to_string())))
+            }
+
+            statement = &statement[(index + 1)..];
+        }
+
+        if let Some(item) = parse_statement(statement).map_err(fault)? {
+            address += item.size();
+            items.push((i + 1, item));
+        }
+    }
+
+    let mut tape = Vec::new();
+    for (line, item) in items {
+        let fault = |kind| AssemblyError { line, kind };
+        match item {
+            Item::Instruction { opcode, operands } => {
+                let mut instruction = opcode;
+                for (i, (mode, _)) in operands.iter().enumerate() {
+                    instruction += mode_digit(*mode) * 10_i64.pow(i as u32 + 2);
+                }
+                tape.push(instruction);
+
+                for (_, term) in &operands {
+                    tape.push(resolve(term, &labels).map_err(fault)?);
+                }
+            },
+            Item::Data(values) => {
+                for term in &values {
+                    tape.push(resolve(term, &labels).map_err(fault)?);
+                }
+            }
+        }
+    }
+
+    Ok(tape)
+}
+
+// `out #value` for each of `values`: the code of a program that only writes,
+// e.g. to stand in for a puzzle program in tests
+pub fn outputs (values: &[i64]) -> Vec<i64> {
+    values.iter().flat_map(|&value| [104, value]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disassembler::listing;
+
+    fn round_trip (tape: &[i64]) {
+        assert_eq!(assemble(&listing(tape)).unwrap(), tape);
+    }
+
+    fn error (source: &str) -> AssemblyError {
+        assemble(source).unwrap_err()
+    }
+
+    #[test]
+    fn assembles_listings () {
+        round_trip(&[1105, 1, 4, 7, 4, 3, 99, 1, 2]);
+        round_trip(&[109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99]);
+        round_trip(&[
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104,
+            999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20, 1105, 1, 46, 98, 99
+        ]);
+    }
+
+    #[test]
+    fn labels_and_data () {
+        let source = "
+            start:  in   x            ; read x
+                    mul  x, #2, rb-1
+                    jt   #1, #start
+            x:      db   0, \"hi\\n\"
+                    ds   2
+        ";
+        assert_eq!(assemble(source).unwrap(), vec![3, 9, 21002, 9, 2, -1, 1105, 1, 0, 0, 104, 105, 10, 0, 0]);
+    }
+
+    #[test]
+    fn output_programs () {
+        let mut tape = outputs(&[7, -1]);
+        assert_eq!(tape, assemble("out #7\nout #-1").unwrap());
+        tape.push(99);
+        assert_eq!(crate::run(&tape, &[]).unwrap(), vec![7, -1]);
+    }
+
+    #[test]
+    fn errors () {
+        assert_eq!(error("jmp 1").kind, AssemblyErrorKind::UnknownMnemonic(String::from("jmp")));
+        assert_eq!(error("add 1, 2").kind, AssemblyErrorKind::OperandCount { expected: 3, found: 2 });
+        assert_eq!(error("add 1, 2, #3").kind, AssemblyErrorKind::ImmediateWrite(String::from("#3")));
+        assert_eq!(error("out x").kind, AssemblyErrorKind::UnknownLabel(String::from("x")));
+        assert_eq!(error("a: hlt\na: hlt").kind, AssemblyErrorKind::DuplicateLabel(String::from("a")));
+        assert_eq!(error("hlt\n0: hlt").kind, AssemblyErrorKind::AddressMismatch { expected: 0, found: 1 });
+        assert_eq!(error("hlt\ndb \"open").to_string(), "line 2: unterminated string");
+        assert_eq!(error("out rb--9223372036854775808").kind, AssemblyErrorKind::InvalidOperand(String::from("rb--9223372036854775808")));
+        assert_eq!(error("ds 1000000000000").kind, AssemblyErrorKind::ReserveTooLarge(1_000_000_000_000));
+    }
+}
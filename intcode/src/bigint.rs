@@ -0,0 +1,210 @@
+use std::convert::TryFrom;
+
+use ibig::IBig;
+
+use crate::decode::{self, Decoded};
+use crate::memory::{self, SparseMemory};
+use crate::operand::{convert_pointer, get_pointer, get_value, Cells};
+use crate::{ErrorKind, IntcodeError};
+
+// A separate machine with arbitrary precision: every cell holds an `IBig`,
+// so `add` and `mul` cannot overflow. It is not an `Arithmetic` policy,
+// because `ProgramState`, its outputs and everything built on them (tracing,
+// history, profiles, snapshots) are i64. It shares the decoder, operand
+// resolution and memory layout with `ProgramState`, but has no budget, cycle
+// detection, tracing, history, code-write flagging or decode cache.
+// Instructions, addresses and the relative base still have to fit in an i64
+// and fail with `ErrorKind::Overflow` otherwise. Much slower than
+// `ProgramState`; meant for tapes that need it.
+#[derive(Default, Clone, Debug)]
+pub struct BigProgramState {
+    pub tape: Vec<IBig>,
+    pub sparse: SparseMemory<IBig>,
+    pub input: Vec<IBig>,
+    pub tape_index: i64,
+    pub input_index: usize,
+    pub relative_base: i64
+}
+
+fn small (value: &IBig) -> Result<i64, ErrorKind> {
+    i64::try_from(value).map_err(|_| ErrorKind::Overflow)
+}
+
+impl Cells for BigProgramState {
+    type Cell = IBig;
+
+    fn cell (&self, address: usize) -> IBig {
+        self.read(address)
+    }
+
+    fn address (cell: &IBig) -> Result<i64, ErrorKind> {
+        small(cell)
+    }
+
+    fn relative_base (&self) -> i64 {
+        self.relative_base
+    }
+}
+
+impl BigProgramState {
+    pub fn new (tape: &[IBig]) -> Self {
+        BigProgramState {
+            tape: tape.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    pub fn read (&self, address: usize) -> IBig {
+        memory::load(&self.tape, &self.sparse, address)
+    }
+
+    pub fn write (&mut self, address: usize, value: IBig) {
+        memory::store(&mut self.tape, &mut self.sparse, address, value)
+    }
+
+    fn decode (&self) -> Result<(i64, Decoded), IntcodeError> {
+        let tape_index = self.tape_index;
+        let fault = |opcode, kind| IntcodeError { tape_index, opcode, kind };
+
+        let address = convert_pointer(tape_index).map_err(|kind| fault(None, kind))?;
+        let instruction = small(&self.read(address)).map_err(|kind| fault(None, kind))?;
+        let decoded = decode::decode(instruction).map_err(|kind| fault(Some(instruction % 100), kind))?;
+        Ok((instruction % 100, decoded))
+    }
+
+    pub fn is_done (&self) -> bool {
+        matches!(self.decode(), Ok((99, _)))
+    }
+
+    pub fn needs_input (&self) -> bool {
+        matches!(self.decode(), Ok((3, _))) && self.input_index >= self.input.len()
+    }
+}
+
+// Executes one instruction like `intcode::do_instruction`, with errors
+// reported the same way and the instruction pointer left on the faulting
+// instruction.
+pub fn do_instruction (state: &mut BigProgramState) -> Result<Option<IBig>, IntcodeError> {
+    let tape_index = state.tape_index;
+    let (opcode, decoded) = state.decode()?;
+    let fault = |kind| IntcodeError { tape_index, opcode: Some(opcode), kind };
+
+    if opcode == 3 && state.input_index >= state.input.len() {
+        return Err(fault(ErrorKind::MissingInput))
+    }
+    let mut next = tape_index.checked_add(decoded.size()).ok_or(fault(ErrorKind::Overflow))?;
+
+    let mut values = Vec::new();
+    for i in 0..decoded.values as usize {
+        values.push(get_value(state, tape_index + 1 + i as i64, decoded.modes[i]).map_err(fault)?);
+    }
+
+    let mut pointer = 0;
+    if decoded.pointers == 1 {
+        let i = decoded.values as usize;
+        pointer = get_pointer(state, tape_index + 1 + i as i64, decoded.modes[i]).map_err(fault)?;
+    }
+
+    let mut output = None;
+    let mut write = None;
+    match opcode {
+        1 => {
+            write = Some(&values[0] + &values[1])
+        },
+        2 => {
+            write = Some(&values[0] * &values[1])
+        },
+        3 => {
+            write = Some(state.input[state.input_index].clone())
+        },
+        4 => {
+            output = Some(values[0].clone())
+        },
+        5 | 6 => {
+            if (values[0] != IBig::from(0)) == (opcode == 5) {
+                next = small(&values[1]).map_err(fault)?
+            }
+        },
+        7 => {
+            write = Some(IBig::from((values[0] < values[1]) as i64))
+        },
+        8 => {
+            write = Some(IBig::from((values[0] == values[1]) as i64))
+        },
+        9 => {
+            let offset = small(&values[0]).map_err(fault)?;
+            state.relative_base = state.relative_base.checked_add(offset).ok_or(fault(ErrorKind::Overflow))?
+        },
+        99 => {},
+        _ => unreachable!()
+    }
+
+    if opcode == 3 {
+        state.input_index += 1;
+    }
+    if let Some(value) = write {
+        state.write(pointer, value);
+    }
+    state.tape_index = next;
+
+    Ok(output)
+}
+
+pub fn step (state: &mut BigProgramState) -> Result<Option<IBig>, IntcodeError> {
+    let mut output = None;
+    while output.is_none() && !state.is_done() {
+        output = do_instruction(state)?;
+    }
+    Ok(output)
+}
+
+pub fn run (intcode: &[IBig], input: &[IBig]) -> Result<Vec<IBig>, IntcodeError> {
+    let mut output = Vec::new();
+    let mut state = BigProgramState {
+        tape: intcode.to_vec(),
+        input: input.to_vec(),
+        ..Default::default()
+    };
+
+    while let Some(step_output) = step(&mut state)? {
+        output.push(step_output)
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big (values: &[i64]) -> Vec<IBig> {
+        values.iter().map(|&value| IBig::from(value)).collect()
+    }
+
+    #[test]
+    fn matches_i64_machine () {
+        let tape = [1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+        let mut state = BigProgramState::new(&big(&tape));
+        while !state.is_done() {
+            do_instruction(&mut state).unwrap();
+        }
+        assert_eq!(state.tape[0], IBig::from(3500));
+    }
+
+    #[test]
+    fn growing_takes_over_sparse_cells () {
+        // 70020 is stored apart from the tape, until writing 80021 grows
+        // the tape past it
+        let tape = [1101, 7, 0, 70020, 1101, 1, 0, 60020, 1101, 1, 0, 80021, 4, 70020, 99];
+        assert_eq!(crate::run(&tape, &[]).unwrap(), vec![7]);
+        assert_eq!(run(&big(&tape), &[]).unwrap(), big(&[7]));
+    }
+
+    #[test]
+    fn no_overflow () {
+        let tape = big(&[1102, i64::MAX, i64::MAX, 7, 4, 7, 99, 0]);
+        let square = IBig::from(i64::MAX) * IBig::from(i64::MAX);
+        assert_eq!(run(&tape, &[]).unwrap(), vec![square]);
+        assert_eq!(crate::run(&[1102, i64::MAX, i64::MAX, 7, 4, 7, 99, 0], &[]).unwrap_err().kind, ErrorKind::Overflow);
+    }
+}
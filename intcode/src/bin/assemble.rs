@@ -0,0 +1,19 @@
+use std::env;
+use std::fs;
+use std::process;
+
+fn main () {
+    let path = env::args().nth(1).unwrap_or_else(|| String::from("./input.asm"));
+    let file = fs::read_to_string(path).expect("unable to read file");
+
+    match intcode::assembler::assemble(&file) {
+        Ok(tape) => {
+            let tape = tape.iter().map(|value| value.to_string()).collect::<Vec<String>>();
+            println!("{}", tape.join(","));
+        },
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
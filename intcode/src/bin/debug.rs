@@ -0,0 +1,34 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+use intcode::debugger::Debugger;
+
+fn parse_number (string: &str) -> i64 {
+    string.trim().parse::<i64>().expect("cannot parse string as int")
+}
+
+fn main () {
+    let path = env::args().nth(1).unwrap_or_else(|| String::from("./input.txt"));
+    let file = fs::read_to_string(path).expect("unable to read file");
+    let tape = file.trim().split(',').map(parse_number).collect::<Vec<i64>>();
+
+    let mut debugger = Debugger::new(intcode::ProgramState::new(&tape));
+    println!("{}", debugger.list(0, 5));
+
+    let stdin = io::stdin();
+    loop {
+        print!("(intcode) ");
+        io::stdout().flush().expect("unable to write prompt");
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).expect("unable to read command") == 0 {
+            break
+        }
+
+        match debugger.command(&line) {
+            Some(response) => println!("{}", response),
+            None => break
+        }
+    }
+}
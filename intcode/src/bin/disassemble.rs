@@ -0,0 +1,14 @@
+use std::env;
+use std::fs;
+
+fn parse_number (string: &str) -> i64 {
+    string.trim().parse::<i64>().expect("cannot parse string as int")
+}
+
+fn main () {
+    let path = env::args().nth(1).unwrap_or_else(|| String::from("./input.txt"));
+    let file = fs::read_to_string(path).expect("unable to read file");
+    let tape = file.trim().split(',').map(parse_number).collect::<Vec<i64>>();
+
+    print!("{}", intcode::disassembler::listing(&tape));
+}
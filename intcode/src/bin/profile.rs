@@ -0,0 +1,26 @@
+use std::env;
+use std::fs;
+
+use intcode::profile::Profiler;
+
+fn parse_number (string: &str) -> i64 {
+    string.trim().parse::<i64>().expect("cannot parse string as int")
+}
+
+// usage: profile <tape> [input...]
+fn main () {
+    let arguments = env::args().collect::<Vec<String>>();
+    let path = arguments.get(1).map_or("./input.txt", |path| path.as_str());
+    let file = fs::read_to_string(path).expect("unable to read file");
+    let tape = file.trim().split(',').map(parse_number).collect::<Vec<i64>>();
+    let input = arguments.iter().skip(2).map(|string| parse_number(string)).collect::<Vec<i64>>();
+
+    let mut profiler = Profiler::default();
+    let result = intcode::run_traced(&tape, &input, &mut profiler);
+
+    print!("{}", profiler.report(Some(&tape), 20));
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,28 @@
+use std::env;
+use std::fs::{self, File};
+use std::io::BufWriter;
+
+use intcode::trace::TraceWriter;
+
+fn parse_number (string: &str) -> i64 {
+    string.trim().parse::<i64>().expect("cannot parse string as int")
+}
+
+// usage: trace <tape> <trace file> [input...]
+fn main () {
+    let arguments = env::args().collect::<Vec<String>>();
+    if arguments.len() < 3 {
+        eprintln!("usage: {} <tape> <trace file> [input...]", arguments[0]);
+        std::process::exit(1);
+    }
+
+    let file = fs::read_to_string(&arguments[1]).expect("unable to read file");
+    let tape = file.trim().split(',').map(parse_number).collect::<Vec<i64>>();
+    let input = arguments[3..].iter().map(|string| parse_number(string)).collect::<Vec<i64>>();
+
+    let mut tracer = TraceWriter::new(BufWriter::new(File::create(&arguments[2]).expect("unable to create trace file")));
+    let result = intcode::run_traced(&tape, &input, &mut tracer);
+    tracer.finish().expect("unable to write trace file");
+
+    println!("{:?}", result.expect("intcode program failed"));
+}
@@ -0,0 +1,194 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::disassembler::{self, Instruction, Mode, Operand};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Successor {
+    // the next block, reached by falling through
+    Next(usize),
+    // an immediate jump target
+    Jump(usize),
+    // an immediate jump target below 0, where the program would fault
+    Invalid(i64),
+    // a jump through a positional or relative operand, only known at runtime
+    Indirect(Operand)
+}
+
+// A run of instructions that is only entered at the top and only left at the
+// bottom. A block without successors halts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub start: usize,
+    pub instructions: Vec<Instruction>,
+    pub successors: Vec<Successor>
+}
+
+impl Block {
+    pub fn end (&self) -> usize {
+        self.instructions.last().map_or(self.start, |instruction| instruction.address + instruction.size())
+    }
+
+    pub fn halts (&self) -> bool {
+        self.successors.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cfg {
+    pub blocks: BTreeMap<usize, Block>
+}
+
+fn is_jump (instruction: &Instruction) -> bool {
+    instruction.opcode == 5 || instruction.opcode == 6
+}
+
+// whether a jump can be taken, judging by an immediate condition
+fn may_jump (instruction: &Instruction) -> bool {
+    match (instruction.opcode, instruction.operands[0]) {
+        (5, Operand { mode: Mode::Immediate, value }) => value != 0,
+        (6, Operand { mode: Mode::Immediate, value }) => value == 0,
+        _ => true
+    }
+}
+
+// Splits the code found by the disassembler into basic blocks. Blocks start
+// at address 0, at jump targets, at stored return addresses and after jumps
+// and halts, and end before the next start or at a gap in the code.
+pub fn build (tape: &[i64]) -> Cfg {
+    let code = disassembler::find_code(tape);
+
+    let mut leaders = BTreeSet::new();
+    leaders.insert(0);
+    for instruction in &code {
+        let targets = instruction.jump_target().into_iter().chain(instruction.stored_constant());
+        leaders.extend(targets.filter(|&target| target >= 0).map(|target| target as usize));
+        if is_jump(instruction) || instruction.opcode == 99 {
+            leaders.insert(instruction.address + instruction.size());
+        }
+    }
+
+    let mut blocks: Vec<Block> = Vec::new();
+    for instruction in code {
+        let extends = blocks.last().is_some_and(|block| {
+            let last = block.instructions.last().unwrap();
+            block.end() == instruction.address && !is_jump(last) && last.opcode != 99
+        });
+
+        if extends && !leaders.contains(&instruction.address) {
+            blocks.last_mut().unwrap().instructions.push(instruction);
+        } else {
+            blocks.push(Block { start: instruction.address, instructions: vec![instruction], successors: Vec::new() });
+        }
+    }
+
+    let starts = blocks.iter().map(|block| block.start).collect::<BTreeSet<usize>>();
+    for block in &mut blocks {
+        let last = block.instructions.last().unwrap();
+
+        if is_jump(last) && may_jump(last) {
+            block.successors.push(match last.jump_target() {
+                Some(target) if target >= 0 => Successor::Jump(target as usize),
+                Some(target) => Successor::Invalid(target),
+                None => Successor::Indirect(last.operands[1])
+            });
+        }
+        if last.falls_through() && starts.contains(&block.end()) {
+            block.successors.push(Successor::Next(block.end()));
+        }
+    }
+
+    Cfg { blocks: blocks.into_iter().map(|block| (block.start, block)).collect() }
+}
+
+impl Cfg {
+    pub fn block_at (&self, address: usize) -> Option<&Block> {
+        self.blocks.range(..=address).next_back().map(|(_, block)| block).filter(|block| address < block.end())
+    }
+
+    // Graphviz DOT, one box per block listing its instructions. Jumps are
+    // solid, fall-through is dotted, indirect jumps are dashed and lead to a
+    // `?` node, invalid jumps lead to a red octagon, and halting blocks have a
+    // double border.
+    pub fn to_dot (&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph intcode {{").unwrap();
+        writeln!(dot, "    node [shape=box, fontname=monospace];").unwrap();
+
+        for block in self.blocks.values() {
+            let label = block.instructions.iter().map(|instruction| instruction.to_string().trim_start().to_string() + "\\l").collect::<String>();
+            let border = if block.halts() { ", peripheries=2" } else { "" };
+            writeln!(dot, "    b{} [label=\"{}\"{}];", block.start, label, border).unwrap();
+
+            for successor in &block.successors {
+                match successor {
+                    Successor::Next(target) => writeln!(dot, "    b{} -> b{} [style=dotted];", block.start, target),
+                    Successor::Jump(target) if self.blocks.contains_key(target) => writeln!(dot, "    b{} -> b{};", block.start, target),
+                    Successor::Jump(target) => {
+                        writeln!(dot, "    x{} [label=\"{}?\", shape=plaintext];", target, target).unwrap();
+                        writeln!(dot, "    b{} -> x{};", block.start, target)
+                    },
+                    Successor::Invalid(target) => {
+                        writeln!(dot, "    v{} [label=\"{}\", shape=octagon, color=red];", block.start, target).unwrap();
+                        writeln!(dot, "    b{} -> v{} [color=red];", block.start, block.start)
+                    },
+                    Successor::Indirect(operand) => {
+                        writeln!(dot, "    i{} [label=\"?\", shape=circle];", block.start).unwrap();
+                        writeln!(dot, "    b{} -> i{} [style=dashed, label=\"{}\"];", block.start, block.start, operand)
+                    }
+                }.unwrap();
+            }
+        }
+
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembler::assemble;
+
+    fn successors (cfg: &Cfg, start: usize) -> Vec<Successor> {
+        cfg.blocks[&start].successors.clone()
+    }
+
+    #[test]
+    fn splits_blocks () {
+        let tape = assemble("
+                    in   x
+            loop:   add  x, #-1, x
+                    jt   x, #loop
+                    out  x
+                    hlt
+            x:      db   0
+        ").unwrap();
+        let cfg = build(&tape);
+
+        assert_eq!(cfg.blocks.keys().copied().collect::<Vec<usize>>(), vec![0, 2, 9]);
+        assert_eq!(successors(&cfg, 0), vec![Successor::Next(2)]);
+        assert_eq!(successors(&cfg, 2), vec![Successor::Jump(2), Successor::Next(9)]);
+        assert!(cfg.blocks[&9].halts());
+        assert_eq!(cfg.block_at(4).map(|block| block.start), Some(2));
+        assert_eq!(cfg.block_at(12), None);
+    }
+
+    #[test]
+    fn indirect_jumps () {
+        let tape = assemble("jt #1, rb+3").unwrap();
+        let successors = successors(&build(&tape), 0);
+        assert_eq!(successors, vec![Successor::Indirect(Operand { mode: Mode::Relative, value: 3 })]);
+    }
+
+    #[test]
+    fn negative_jump_targets () {
+        let tape = assemble("jt x, #-5\nhlt\nx: db 1").unwrap();
+        let cfg = build(&tape);
+        assert_eq!(successors(&cfg, 0), vec![Successor::Invalid(-5), Successor::Next(3)]);
+
+        let dot = cfg.to_dot();
+        assert!(dot.contains("    v0 [label=\"-5\", shape=octagon, color=red];\n    b0 -> v0 [color=red];\n"));
+        assert!(dot.contains("    b0 -> b3 [style=dotted];\n"));
+    }
+}
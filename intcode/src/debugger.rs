@@ -0,0 +1,396 @@
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::disassembler::{self, Line};
+use crate::history::{self, History};
+use crate::modification::{CodeWrite, Modifications};
+use crate::{do_instruction, IntcodeError, ProgramState};
+
+const HELP: &str = "\
+s, step [n]          execute n instructions (default 1)
+c, continue          run until a breakpoint, watchpoint, write to executed
+                     code, halt or missing input
+bs, back [n]         undo n instructions (default 1)
+bw, backwrite <addr> run back to just before the last write of addr
+b, break [addr]      set a breakpoint, or list breakpoints
+d, delete <addr>     remove a breakpoint
+w, watch [addr]      set a watchpoint, or list watchpoints
+u, unwatch <addr>    remove a watchpoint
+p, print <addr> [n]  show n cells of memory (default 1)
+set <addr> <value>   change a memory cell
+ip [value]           show or change the instruction pointer
+rb [value]           show or change the relative base
+                     (changes clear the history, see back)
+i, input [values]    queue input values, or show pending input
+l, list [addr]       disassemble around addr (default ip)
+r, regs              show the machine registers
+q, quit              leave the debugger";
+
+// instructions kept for stepping backwards
+const HISTORY: usize = 1_000_000;
+
+// most cells `print` shows at once
+const PRINT_LIMIT: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Stepped,
+    Breakpoint(i64),
+    Watchpoint { address: usize, old: i64, new: i64 },
+    CodeWrite(CodeWrite),
+    NeedsInput,
+    Halted,
+    Error(IntcodeError)
+}
+
+impl fmt::Display for StopReason {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopReason::Stepped => write!(f, "stepped"),
+            StopReason::Breakpoint(address) => write!(f, "breakpoint at {}", address),
+            StopReason::Watchpoint { address, old, new } => write!(f, "watchpoint on {}: {} -> {}", address, old, new),
+            StopReason::CodeWrite(write) => write!(f, "{}", write),
+            StopReason::NeedsInput => write!(f, "waiting for input"),
+            StopReason::Halted => write!(f, "halted"),
+            StopReason::Error(error) => write!(f, "error: {}", error)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Debugger {
+    pub state: ProgramState,
+    pub breakpoints: BTreeSet<i64>,
+    pub watchpoints: BTreeSet<usize>,
+    pub output: Vec<i64>
+}
+
+impl Debugger {
+    // starts recording the machine's history and flagging writes to code,
+    // if the machine was not doing so already
+    pub fn new (mut state: ProgramState) -> Self {
+        if !state.history.is_recording() {
+            state.history = History::new(HISTORY);
+        }
+        if !state.modifications.is_enabled() {
+            state.modifications = Modifications::new();
+        }
+        Debugger {
+            state,
+            ..Default::default()
+        }
+    }
+
+    fn read (&self, address: usize) -> i64 {
+        self.state.read(address)
+    }
+
+    fn check_halt (&self) -> Option<StopReason> {
+        if self.state.is_done() {
+            Some(StopReason::Halted)
+        } else if self.state.needs_input() {
+            Some(StopReason::NeedsInput)
+        } else {
+            None
+        }
+    }
+
+    // executes a single instruction, ignoring breakpoints
+    pub fn step (&mut self) -> StopReason {
+        if let Some(reason) = self.check_halt() {
+            return reason
+        }
+
+        let watched = self.watchpoints.iter().map(|&address| (address, self.read(address))).collect::<Vec<(usize, i64)>>();
+        let code_writes = self.state.modifications.writes.len();
+
+        match do_instruction(&mut self.state) {
+            Ok(Some(value)) => self.output.push(value),
+            Ok(None) => {},
+            Err(error) => return StopReason::Error(error)
+        }
+
+        if let Some(&write) = self.state.modifications.writes.get(code_writes) {
+            return StopReason::CodeWrite(write)
+        }
+
+        for (address, old) in watched {
+            let new = self.read(address);
+            if new != old {
+                return StopReason::Watchpoint { address, old, new }
+            }
+        }
+
+        StopReason::Stepped
+    }
+
+    // runs until something stops the machine; a breakpoint on the current
+    // instruction does not stop it, so that continuing from one works
+    pub fn resume (&mut self) -> StopReason {
+        let mut first = true;
+        loop {
+            if !first && self.breakpoints.contains(&self.state.tape_index) {
+                return StopReason::Breakpoint(self.state.tape_index)
+            }
+            first = false;
+
+            match self.step() {
+                StopReason::Stepped => {},
+                reason => return reason
+            }
+        }
+    }
+
+    // disassembly of the code before `address` (as far as the listing knows
+    // it) and a linear decode from `address` onwards
+    pub fn list (&self, address: i64, context: usize) -> String {
+        let mut lines = disassembler::disassemble(&self.state.tape)
+            .into_iter()
+            .filter(|line| match line {
+                Line::Code(instruction) => ((instruction.address + instruction.size()) as i64) <= address,
+                Line::Data { .. } => false
+            })
+            .map(|line| format!("  {}", line))
+            .collect::<Vec<String>>();
+        lines.drain(..lines.len().saturating_sub(context));
+
+        let mut next = address.max(0) as usize;
+        for _ in 0..context {
+            let marker = if next as i64 == self.state.tape_index { ">" } else { " " };
+            match disassembler::decode(&self.state.tape, next) {
+                Some(instruction) => {
+                    next += instruction.size();
+                    lines.push(format!("{} {}", marker, instruction));
+                },
+                None if next < self.state.tape.len() => {
+                    lines.push(format!("{} {}", marker, Line::Data { address: next, values: vec![self.read(next)] }));
+                    next += 1;
+                },
+                None => break
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    fn registers (&self) -> String {
+        let pending = &self.state.input[self.state.input_index.min(self.state.input.len())..];
+        format!(
+            "ip {} rb {} input {:?} output {:?}",
+            self.state.tape_index,
+            self.state.relative_base,
+            pending,
+            self.output
+        )
+    }
+
+    // describes the undone instructions, most recent first
+    fn undone (&self, changes: &[history::Change]) -> String {
+        if changes.is_empty() {
+            return String::from("no history to go back to\n")
+        }
+        changes.iter().map(|change| format!("undid {}\n", change)).collect()
+    }
+
+    // Undoing instructions from before an edit would put their old values
+    // back over it, so edits clear the history instead.
+    fn edited (&mut self, response: String) -> String {
+        if self.state.history.is_empty() {
+            return response
+        }
+        self.state.history.clear();
+        format!("{}\nhistory cleared, cannot go back past this edit", response)
+    }
+
+    fn take_output (&mut self) -> String {
+        self.output.drain(..).map(|value| format!("output: {}\n", value)).collect()
+    }
+
+    // executes a debugger command and returns what to print, or `None` to quit
+    pub fn command (&mut self, line: &str) -> Option<String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("");
+        let arguments = words.map(|word| word.parse::<i64>()).collect::<Result<Vec<i64>, _>>();
+        let arguments = match arguments {
+            Ok(arguments) => arguments,
+            Err(_) => return Some(String::from("arguments must be integers"))
+        };
+        let address = arguments.first().map(|&address| address as usize);
+
+        let uses_memory = ["p", "print", "set", "w", "watch", "u", "unwatch", "bw", "backwrite"].contains(&command);
+        if uses_memory && arguments.first().is_some_and(|&address| address < 0) {
+            return Some(String::from("addresses cannot be negative"))
+        }
+
+        let response = match (command, arguments.as_slice()) {
+            ("", _) => String::new(),
+            ("h", _) | ("help", _) => String::from(HELP),
+            ("q", _) | ("quit", _) => return None,
+            ("s", _) | ("step", _) => {
+                let mut reason = StopReason::Stepped;
+                for _ in 0..arguments.first().copied().unwrap_or(1) {
+                    reason = self.step();
+                    if reason != StopReason::Stepped {
+                        break
+                    }
+                }
+                format!("{}{}\n{}", self.take_output(), reason, self.list(self.state.tape_index, 1))
+            },
+            ("c", _) | ("continue", _) => {
+                let reason = self.resume();
+                format!("{}{}\n{}", self.take_output(), reason, self.list(self.state.tape_index, 1))
+            },
+            ("bs", _) | ("back", _) => {
+                let count = arguments.first().copied().unwrap_or(1);
+                let changes = (0..count).map_while(|_| history::step_back(&mut self.state)).collect::<Vec<history::Change>>();
+                format!("{}{}", self.undone(&changes), self.list(self.state.tape_index, 1))
+            },
+            ("bw", [_]) | ("backwrite", [_]) => {
+                let before = self.state.history.len();
+                match history::run_back_to_write(&mut self.state, address.unwrap()) {
+                    Some(change) => format!(
+                        "undid {} instructions, back to {}\n{}",
+                        before - self.state.history.len(),
+                        change,
+                        self.list(self.state.tape_index, 1)
+                    ),
+                    None => format!("no write to {} in the history", address.unwrap())
+                }
+            },
+            ("b", []) | ("break", []) => format!("breakpoints: {:?}", self.breakpoints),
+            ("b", [address]) | ("break", [address]) => {
+                self.breakpoints.insert(*address);
+                format!("breakpoint at {}", address)
+            },
+            ("d", [address]) | ("delete", [address]) => {
+                self.breakpoints.remove(address);
+                format!("removed breakpoint at {}", address)
+            },
+            ("w", []) | ("watch", []) => format!("watchpoints: {:?}", self.watchpoints),
+            ("w", [_]) | ("watch", [_]) => {
+                self.watchpoints.insert(address.unwrap());
+                format!("watching {}", address.unwrap())
+            },
+            ("u", [_]) | ("unwatch", [_]) => {
+                self.watchpoints.remove(&address.unwrap());
+                format!("no longer watching {}", address.unwrap())
+            },
+            ("p", [_]) | ("print", [_]) | ("p", [_, _]) | ("print", [_, _]) => {
+                let start = address.unwrap();
+                let count = arguments.get(1).copied().unwrap_or(1);
+                match usize::try_from(count) {
+                    Err(_) => String::from("count cannot be negative"),
+                    Ok(count) if count > PRINT_LIMIT => format!("cannot print more than {} cells", PRINT_LIMIT),
+                    Ok(count) => match start.checked_add(count) {
+                        Some(end) => {
+                            let values = (start..end).map(|address| self.read(address)).collect::<Vec<i64>>();
+                            format!("{}: {:?}", start, values)
+                        },
+                        None => String::from("addresses out of range")
+                    }
+                }
+            },
+            ("set", [_, value]) => {
+                let address = address.unwrap();
+                self.state.write(address, *value);
+                self.edited(format!("{}: {}", address, value))
+            },
+            ("ip", []) => format!("ip {}", self.state.tape_index),
+            ("ip", [value]) => {
+                self.state.tape_index = *value;
+                self.edited(format!("ip {}", value))
+            },
+            ("rb", []) => format!("rb {}", self.state.relative_base),
+            ("rb", [value]) => {
+                self.state.relative_base = *value;
+                self.edited(format!("rb {}", value))
+            },
+            ("i", []) | ("input", []) => self.registers(),
+            ("i", values) | ("input", values) => {
+                self.state.input.extend_from_slice(values);
+                self.registers()
+            },
+            ("l", []) | ("list", []) => self.list(self.state.tape_index, 5),
+            ("l", [address]) | ("list", [address]) => self.list(*address, 5),
+            ("r", _) | ("regs", _) => self.registers(),
+            _ => format!("unknown command: {} (try help)", line.trim())
+        };
+
+        Some(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debugger (tape: &[i64]) -> Debugger {
+        Debugger::new(ProgramState::new(tape))
+    }
+
+    #[test]
+    fn print () {
+        let mut debugger = debugger(&[1, 2, 3, 99]);
+        assert_eq!(debugger.command("p 1 2").unwrap(), "1: [2, 3]");
+        assert_eq!(debugger.command("p 3").unwrap(), "3: [99]");
+        assert_eq!(debugger.command("p 5 0").unwrap(), "5: []");
+    }
+
+    #[test]
+    fn print_rejects_bad_counts () {
+        let mut debugger = debugger(&[99]);
+        assert_eq!(debugger.command("p 5 -1").unwrap(), "count cannot be negative");
+        assert_eq!(debugger.command("p 0 1000000000000").unwrap(), "cannot print more than 10000 cells");
+        assert_eq!(debugger.command(&format!("p {} 2", i64::MAX)).unwrap(), "9223372036854775807: [0, 0]");
+        assert_eq!(debugger.command("p -1").unwrap(), "addresses cannot be negative");
+    }
+
+    #[test]
+    fn breakpoints_and_watchpoints () {
+        // counts down from 3 to 0 in cell 9
+        let tape = [1001, 9, -1, 9, 1005, 9, 0, 99, 0, 3];
+        let mut debugger = debugger(&tape);
+        debugger.command("b 4");
+        assert_eq!(debugger.resume(), StopReason::Breakpoint(4));
+
+        debugger.command("d 4");
+        debugger.command("w 9");
+        assert_eq!(debugger.resume(), StopReason::Watchpoint { address: 9, old: 2, new: 1 });
+        debugger.command("u 9");
+        assert_eq!(debugger.resume(), StopReason::Halted);
+        assert_eq!(debugger.state.read(9), 0);
+    }
+
+    #[test]
+    fn steps_back () {
+        let mut debugger = debugger(&[1101, 2, 3, 5, 99, 0]);
+        assert_eq!(debugger.resume(), StopReason::Halted);
+        assert_eq!(debugger.state.read(5), 5);
+        debugger.command("bs");
+        assert_eq!(debugger.state.tape_index, 0);
+        assert_eq!(debugger.state.read(5), 0);
+    }
+
+    #[test]
+    fn edits_clear_the_history () {
+        let mut debugger = debugger(&[1101, 2, 3, 5, 99, 0]);
+        assert_eq!(debugger.command("set 5 7").unwrap(), "5: 7");
+        debugger.resume();
+        assert_eq!(debugger.command("set 5 9").unwrap(), "5: 9\nhistory cleared, cannot go back past this edit");
+        assert!(debugger.command("bs").unwrap().starts_with("no history to go back to"));
+        assert_eq!(debugger.state.read(5), 9);
+
+        debugger.command("ip 0");
+        debugger.step();
+        assert_eq!(debugger.command("rb 3").unwrap(), "rb 3\nhistory cleared, cannot go back past this edit");
+        assert!(debugger.state.history.is_empty());
+    }
+
+    #[test]
+    fn stops_on_code_writes () {
+        // overwrites its own opcode
+        let mut debugger = debugger(&[1101, 5, 0, 0, 99]);
+        assert_eq!(debugger.resume(), StopReason::CodeWrite(CodeWrite { writer: 0, address: 0, old: 1101, new: 5 }));
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+
+use crate::{opcode, ErrorKind};
+
+// An instruction word split into its opcode and the modes of its
+// parameters, so that executing it again needs no arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decoded {
+    pub opcode: u8,
+    pub modes: [u8; 3],
+    pub values: u8,
+    pub pointers: u8
+}
+
+impl Decoded {
+    pub fn size (&self) -> i64 {
+        1 + self.values as i64 + self.pointers as i64
+    }
+}
+
+pub fn decode (instruction: i64) -> Result<Decoded, ErrorKind> {
+    let opcode = instruction % 100;
+    let (values, pointers) = opcode::parameters(opcode).ok_or(ErrorKind::UnknownOpcode)?;
+
+    let mut modes = [0; 3];
+    let mut remaining = instruction / 100;
+    for mode in modes.iter_mut().take((values + pointers) as usize) {
+        *mode = match remaining % 10 {
+            digit @ 0..=2 => digit as u8,
+            digit => return Err(ErrorKind::UnknownMode(digit))
+        };
+        remaining /= 10;
+    }
+
+    Ok(Decoded {
+        opcode: opcode as u8,
+        modes,
+        values: values as u8,
+        pointers: pointers as u8
+    })
+}
+
+// Decoded instructions by address. Each entry remembers the word it was
+// decoded from and is only used while the tape still holds that word, so
+// self-modifying code (or a caller editing `tape`) never runs stale code.
+#[derive(Default, Clone)]
+pub struct DecodeCache {
+    entries: Vec<Option<(i64, Decoded)>>
+}
+
+impl DecodeCache {
+    pub fn get (&mut self, address: usize, instruction: i64) -> Result<Decoded, ErrorKind> {
+        if let Some(Some((word, decoded))) = self.entries.get(address) {
+            if *word == instruction {
+                return Ok(*decoded)
+            }
+        }
+
+        let decoded = decode(instruction)?;
+        if address >= self.entries.len() {
+            self.entries.resize(address + 1, None);
+        }
+        self.entries[address] = Some((instruction, decoded));
+        Ok(decoded)
+    }
+
+    pub fn clear (&mut self) {
+        self.entries.clear()
+    }
+}
+
+impl fmt::Debug for DecodeCache {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.entries.iter().filter(|entry| entry.is_some()).count();
+        write!(f, "DecodeCache({} instructions)", count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembler::assemble;
+    use crate::run;
+
+    #[test]
+    fn decodes () {
+        assert_eq!(decode(21002).unwrap(), Decoded { opcode: 2, modes: [0, 1, 2], values: 2, pointers: 1 });
+        assert_eq!(decode(99).unwrap().size(), 1);
+        assert_eq!(decode(42), Err(ErrorKind::UnknownOpcode));
+        assert_eq!(decode(304), Err(ErrorKind::UnknownMode(3)));
+    }
+
+    #[test]
+    fn cache_follows_the_tape () {
+        let mut cache = DecodeCache::default();
+        assert_eq!(cache.get(3, 104).unwrap().modes[0], 1);
+        assert_eq!(cache.get(3, 4).unwrap().modes[0], 0);
+        assert_eq!(cache.get(3, 42), Err(ErrorKind::UnknownOpcode));
+    }
+
+    #[test]
+    fn runs_self_modifying_code () {
+        // the second time round `out #cell` has become `out cell`
+        let source = "
+            target: out  #cell
+                    jt   done, #halt
+                    add  #4, #0, target
+                    add  #1, #0, done
+                    jt   #1, #target
+            halt:   hlt
+            done:   db   0
+            cell:   db   42
+        ";
+        let tape = assemble(source).unwrap();
+        assert_eq!(run(&tape, &[]).unwrap(), vec![tape.len() as i64 - 1, 42]);
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+use crate::{decode, opcode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Position,
+    Immediate,
+    Relative
+}
+
+impl Mode {
+    // `decode::decode` only accepts modes 0 to 2
+    fn from (mode: u8) -> Self {
+        match mode {
+            0 => Mode::Position,
+            1 => Mode::Immediate,
+            2 => Mode::Relative,
+            _ => unreachable!()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operand {
+    pub mode: Mode,
+    pub value: i64
+}
+
+impl fmt::Display for Operand {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Mode::Position => write!(f, "{}", self.value),
+            Mode::Immediate => write!(f, "#{}", self.value),
+            Mode::Relative if self.value < 0 => write!(f, "rb{}", self.value),
+            Mode::Relative => write!(f, "rb+{}", self.value)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opcode: i64,
+    pub operands: Vec<Operand>
+}
+
+impl Instruction {
+    // number of cells, including the instruction itself
+    pub fn size (&self) -> usize {
+        1 + self.operands.len()
+    }
+
+    pub fn mnemonic (&self) -> &'static str {
+        opcode::mnemonic(self.opcode).unwrap()
+    }
+
+    // statically known jump target, if any
+    pub(crate) fn jump_target (&self) -> Option<i64> {
+        match self.opcode {
+            5 | 6 if self.operands[1].mode == Mode::Immediate => Some(self.operands[1].value),
+            _ => None
+        }
+    }
+
+    pub(crate) fn falls_through (&self) -> bool {
+        let condition = self.operands.first().filter(|operand| operand.mode == Mode::Immediate);
+        match (self.opcode, condition) {
+            (99, _) => false,
+            (5, Some(operand)) => operand.value == 0,
+            (6, Some(operand)) => operand.value != 0,
+            _ => true
+        }
+    }
+
+    // `add #x, #0, _` and `mul #x, #1, _` store the constant `x`, which is
+    // how puzzle programs push return addresses before calling a function
+    pub(crate) fn stored_constant (&self) -> Option<i64> {
+        let identity = match self.opcode {
+            1 => 0,
+            2 => 1,
+            _ => return None
+        };
+        let a = self.operands[0];
+        let b = self.operands[1];
+
+        if a.mode != Mode::Immediate || b.mode != Mode::Immediate {
+            None
+        } else if b.value == identity {
+            Some(a.value)
+        } else if a.value == identity {
+            Some(b.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.operands.is_empty() {
+            return write!(f, "{:>5}: {}", self.address, self.mnemonic())
+        }
+
+        let operands = self.operands.iter().map(|operand| operand.to_string()).collect::<Vec<String>>();
+        write!(f, "{:>5}: {:<4}{}", self.address, self.mnemonic(), operands.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Code(Instruction),
+    Data { address: usize, values: Vec<i64> }
+}
+
+impl fmt::Display for Line {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Code(instruction) => write!(f, "{}", instruction),
+            Line::Data { address, values } => {
+                let values = values.iter().map(|value| value.to_string()).collect::<Vec<String>>();
+                write!(f, "{:>5}: {:<4}{}", address, "db", values.join(", "))
+            }
+        }
+    }
+}
+
+// Decodes the instruction at `address` with the same rules as the machine
+// (`decode::decode`), or `None` if the machine would fail on it (unknown
+// opcode or mode) or it runs off the tape. Like the machine, mode digits
+// beyond the opcode's operands are ignored.
+pub fn decode (tape: &[i64], address: usize) -> Option<Instruction> {
+    let decoded = decode::decode(*tape.get(address)?).ok()?;
+    let count = (decoded.values + decoded.pointers) as usize;
+
+    let mut operands = Vec::new();
+    for i in 0..count {
+        operands.push(Operand {
+            mode: Mode::from(decoded.modes[i]),
+            value: *tape.get(address + 1 + i)?
+        });
+    }
+
+    Some(Instruction { address, opcode: decoded.opcode as i64, operands })
+}
+
+// Separates code from data by following the control flow from address 0:
+// fall-through, immediate jump targets and stored constants that look like
+// return addresses. Whatever is never reached is treated as data.
+pub(crate) fn find_code (tape: &[i64]) -> Vec<Instruction> {
+    let mut code = Vec::new();
+    let mut covered = HashSet::new();
+    let mut queue = vec![0];
+
+    while let Some(address) = queue.pop() {
+        if covered.contains(&address) {
+            continue
+        }
+
+        let instruction = match decode(tape, address) {
+            Some(instruction) => instruction,
+            None => continue
+        };
+
+        let span = address..(address + instruction.size());
+        if span.clone().any(|i| covered.contains(&i)) {
+            continue
+        }
+        covered.extend(span);
+
+        let targets = instruction.jump_target().into_iter().chain(instruction.stored_constant());
+        for target in targets {
+            if target >= 0 && (target as usize) < tape.len() {
+                queue.push(target as usize);
+            }
+        }
+
+        if instruction.falls_through() {
+            queue.push(address + instruction.size());
+        }
+
+        code.push(instruction);
+    }
+
+    code.sort_by_key(|instruction| instruction.address);
+    code
+}
+
+pub fn disassemble (tape: &[i64]) -> Vec<Line> {
+    let code = find_code(tape);
+    let starts = code.iter().map(|instruction| instruction.address).collect::<BTreeSet<usize>>();
+
+    let mut lines = Vec::new();
+    let mut code = code.into_iter().peekable();
+    let mut address = 0;
+
+    while address < tape.len() {
+        if starts.contains(&address) {
+            let instruction = code.next().unwrap();
+            address += instruction.size();
+            lines.push(Line::Code(instruction));
+        } else {
+            let end = code.peek().map_or(tape.len(), |instruction| instruction.address);
+            for (i, chunk) in tape[address..end].chunks(8).enumerate() {
+                lines.push(Line::Data { address: address + i * 8, values: chunk.to_vec() });
+            }
+            address = end;
+        }
+    }
+
+    lines
+}
+
+pub fn listing (tape: &[i64]) -> String {
+    disassemble(tape).iter().map(|line| line.to_string() + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_modes () {
+        let instruction = decode(&[21101, 5, -3, 7], 0).unwrap();
+        assert_eq!(instruction.mnemonic(), "add");
+        assert_eq!(instruction.size(), 4);
+        assert_eq!(instruction.to_string(), "    0: add #5, #-3, rb+7");
+        assert_eq!(decode(&[21101, 5, -3, -7], 0).unwrap().operands[2].to_string(), "rb-7");
+    }
+
+    #[test]
+    fn rejects_invalid_instructions () {
+        assert_eq!(decode(&[42], 0), None);
+        assert_eq!(decode(&[301, 0, 0, 0], 0), None);
+        assert_eq!(decode(&[-1], 0), None);
+        // missing operands
+        assert_eq!(decode(&[1, 0], 0), None);
+    }
+
+    #[test]
+    fn ignores_extra_modes_like_the_machine () {
+        let tape = [1104, 7, 99];
+        assert_eq!(crate::run(&tape, &[]).unwrap(), vec![7]);
+        assert_eq!(listing(&tape), "    0: out #7\n    2: hlt\n");
+        assert_eq!(decode(&[10099], 0).unwrap().mnemonic(), "hlt");
+    }
+
+    #[test]
+    fn separates_code_from_data () {
+        // jumps over a data cell; the cells after the halt are never reached
+        let tape = [1105, 1, 4, 7, 4, 3, 99, 1, 2];
+        let lines = ["    0: jt  #1, #4", "    3: db  7", "    4: out 3", "    6: hlt", "    7: db  1, 2"];
+        assert_eq!(listing(&tape), lines.join("\n") + "\n");
+    }
+}
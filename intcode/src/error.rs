@@ -0,0 +1,46 @@
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    NegativePointer(i64),
+    UnknownMode(i64),
+    UnknownOpcode,
+    MissingInput,
+    BudgetExhausted,
+    InfiniteLoop,
+    Overflow
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NegativePointer(pointer) => write!(f, "pointer out of bounds: {}", pointer),
+            ErrorKind::UnknownMode(mode) => write!(f, "unknown parameter mode: {}", mode),
+            ErrorKind::UnknownOpcode => write!(f, "unknown opcode"),
+            ErrorKind::MissingInput => write!(f, "no input available"),
+            ErrorKind::BudgetExhausted => write!(f, "instruction budget exhausted"),
+            ErrorKind::InfiniteLoop => write!(f, "stuck in an infinite loop"),
+            ErrorKind::Overflow => write!(f, "integer overflow")
+        }
+    }
+}
+
+// `opcode` is `None` when the instruction itself could not be fetched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntcodeError {
+    pub tape_index: i64,
+    pub opcode: Option<i64>,
+    pub kind: ErrorKind
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.opcode {
+            Some(opcode) => write!(f, "{} (opcode {} at {})", self.kind, opcode, self.tape_index),
+            None => write!(f, "{} (at {})", self.kind, self.tape_index)
+        }
+    }
+}
+
+impl error::Error for IntcodeError {}
@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::{limits, ProgramState};
+
+// Everything needed to undo one instruction: the registers before it ran,
+// the value its write overwrote, and the input it consumed. The output is
+// kept so that tools can tell what is being taken back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub tape_index: i64,
+    pub relative_base: i64,
+    pub write: Option<(usize, i64)>,
+    pub input: Option<i64>,
+    pub output: Option<i64>
+}
+
+// `ip rb=base [address]<-old <input >output`
+impl fmt::Display for Change {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rb={}", self.tape_index, self.relative_base)?;
+        if let Some((address, old)) = self.write {
+            write!(f, " [{}]<-{}", address, old)?;
+        }
+        if let Some(input) = self.input {
+            write!(f, " <{}", input)?;
+        }
+        if let Some(output) = self.output {
+            write!(f, " >{}", output)?;
+        }
+        Ok(())
+    }
+}
+
+// The undo log of a machine. Nothing is recorded by default; `new` starts
+// recording and keeps at most the last `capacity` instructions.
+#[derive(Default, Clone, Debug)]
+pub struct History {
+    recording: bool,
+    capacity: usize,
+    changes: VecDeque<Change>
+}
+
+impl History {
+    pub fn new (capacity: usize) -> Self {
+        History { recording: true, capacity, changes: VecDeque::new() }
+    }
+
+    pub fn is_recording (&self) -> bool {
+        self.recording
+    }
+
+    pub fn len (&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty (&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    // forgets every change, but keeps recording
+    pub fn clear (&mut self) {
+        self.changes.clear()
+    }
+
+    // oldest first
+    pub fn changes (&self) -> impl DoubleEndedIterator<Item = &Change> {
+        self.changes.iter()
+    }
+
+    pub(crate) fn record (&mut self, change: Change) {
+        if self.changes.len() == self.capacity {
+            self.changes.pop_front();
+        }
+        if self.capacity > 0 {
+            self.changes.push_back(change);
+        }
+    }
+}
+
+// Undoes the last recorded instruction, leaving the instruction pointer on
+// it. Input it consumed will be read again; output cannot be taken back from
+// whoever received it, but is returned as part of the change.
+pub fn step_back (state: &mut ProgramState) -> Option<Change> {
+    let change = state.history.changes.pop_back()?;
+
+    if let Some((address, old)) = change.write {
+        state.write(address, old);
+    }
+    if let Some(input) = change.input {
+        // `io::run_device` drops input once it is consumed
+        if state.input_index == 0 {
+            state.input.insert(0, input);
+        } else {
+            state.input_index -= 1;
+        }
+    }
+    state.tape_index = change.tape_index;
+    state.relative_base = change.relative_base;
+    limits::rewind(state);
+
+    Some(change)
+}
+
+// Steps back until just before the last recorded write to `address`, and
+// returns that write. When the history has no such write nothing is undone.
+pub fn run_back_to_write (state: &mut ProgramState, address: usize) -> Option<Change> {
+    let writes = |change: &Change| change.write.is_some_and(|(written, _)| written == address);
+    state.history.changes().rev().find(|change| writes(change))?;
+
+    loop {
+        let change = step_back(state)?;
+        if writes(&change) {
+            return Some(change)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{do_instruction, run};
+
+    // reads two numbers and outputs their sum, then the first
+    const TAPE: [i64; 16] = [3, 13, 3, 14, 1, 13, 14, 15, 4, 15, 4, 13, 99, 0, 0, 0];
+
+    fn recording (input: &[i64]) -> ProgramState {
+        let mut state = ProgramState::new(&TAPE);
+        state.input = input.to_vec();
+        state.history = History::new(100);
+        state
+    }
+
+    #[test]
+    fn rewinds_to_the_start () {
+        let mut state = recording(&[3, 4]);
+        for _ in 0..5 {
+            do_instruction(&mut state).unwrap();
+        }
+        assert_eq!(state.history.len(), 5);
+
+        let change = step_back(&mut state).unwrap();
+        assert_eq!(change.output, Some(3));
+        while step_back(&mut state).is_some() {}
+
+        assert_eq!(state.tape, TAPE.to_vec());
+        assert_eq!((state.tape_index, state.input_index), (0, 0));
+        assert!(state.history.is_empty());
+    }
+
+    #[test]
+    fn replays_the_same () {
+        let mut state = recording(&[3, 4]);
+        let mut output = Vec::new();
+        while let Some(value) = crate::step(&mut state).unwrap() {
+            output.push(value);
+        }
+        assert_eq!(output, run(&TAPE, &[3, 4]).unwrap());
+
+        while step_back(&mut state).is_some() {}
+        state.input = vec![5, 6];
+        let mut replayed = Vec::new();
+        while let Some(value) = crate::step(&mut state).unwrap() {
+            replayed.push(value);
+        }
+        assert_eq!(replayed, run(&TAPE, &[5, 6]).unwrap());
+    }
+
+    #[test]
+    fn rewinds_dropped_input () {
+        let mut state = recording(&[]);
+        crate::io::run_to_halt(&mut state, &mut crate::io::iter(vec![3, 4]), &mut Vec::new()).unwrap();
+        assert_eq!(state.input, vec![4]);
+
+        while step_back(&mut state).is_some() {}
+        assert_eq!((state.input.clone(), state.input_index), (vec![3, 4], 0));
+        assert_eq!(crate::step(&mut state).unwrap(), Some(7));
+    }
+
+    #[test]
+    fn runs_back_to_a_write () {
+        let mut state = recording(&[3, 4]);
+        while !state.is_done() {
+            do_instruction(&mut state).unwrap();
+        }
+
+        let change = run_back_to_write(&mut state, 14).unwrap();
+        assert_eq!(change.input, Some(4));
+        assert_eq!(state.tape_index, 2);
+        assert_eq!(state.read(14), 0);
+        assert_eq!(run_back_to_write(&mut state, 99), None);
+        assert_eq!(state.tape_index, 2);
+    }
+
+    #[test]
+    fn keeps_the_last_changes () {
+        let mut state = recording(&[3, 4]);
+        state.history = History::new(2);
+        while !state.is_done() {
+            do_instruction(&mut state).unwrap();
+        }
+        assert_eq!(state.history.len(), 2);
+        assert_eq!(state.history.changes().map(|change| change.tape_index).collect::<Vec<i64>>(), vec![8, 10]);
+    }
+}
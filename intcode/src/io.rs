@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{Receiver, Sender};
+
+use crate::{do_instruction, ErrorKind, IntcodeError, ProgramState};
+
+// `None` means no input is available right now, which pauses the machine
+pub trait Input {
+    fn read (&mut self) -> Option<i64>;
+}
+
+pub trait Output {
+    fn write (&mut self, value: i64);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Halted,
+    WaitingForInput
+}
+
+// Appends a value read from a device to `state.input`, dropping the input
+// that has already been consumed, so that a machine fed by a device for a
+// long time doesn't keep every value it ever read.
+fn feed (state: &mut ProgramState, value: i64) {
+    state.input.drain(..state.input_index);
+    state.input_index = 0;
+    state.input.push(value);
+}
+
+// Runs until the program halts or `device` has no input for it.
+pub fn run_device<D: Input + Output + ?Sized> (state: &mut ProgramState, device: &mut D) -> Result<Status, IntcodeError> {
+    loop {
+        if state.is_done() {
+            return Ok(Status::Halted)
+        }
+
+        if state.needs_input() {
+            match device.read() {
+                Some(value) => feed(state, value),
+                None => return Ok(Status::WaitingForInput)
+            }
+        }
+
+        if let Some(value) = do_instruction(state)? {
+            device.write(value);
+        }
+    }
+}
+
+struct Split<'a> (&'a mut dyn Input, &'a mut dyn Output);
+
+impl Input for Split<'_> {
+    fn read (&mut self) -> Option<i64> {
+        self.0.read()
+    }
+}
+
+impl Output for Split<'_> {
+    fn write (&mut self, value: i64) {
+        self.1.write(value)
+    }
+}
+
+pub fn run_io (state: &mut ProgramState, input: &mut dyn Input, output: &mut dyn Output) -> Result<Status, IntcodeError> {
+    run_device(state, &mut Split(input, output))
+}
+
+// like `run_io`, but running out of input is an error
+pub fn run_to_halt (state: &mut ProgramState, input: &mut dyn Input, output: &mut dyn Output) -> Result<(), IntcodeError> {
+    match run_io(state, input, output)? {
+        Status::Halted => Ok(()),
+        Status::WaitingForInput => Err(IntcodeError {
+            tape_index: state.tape_index,
+            opcode: Some(3),
+            kind: ErrorKind::MissingInput
+        })
+    }
+}
+
+pub struct FromFn<F> (F);
+
+pub fn from_fn<F: FnMut() -> Option<i64>> (f: F) -> FromFn<F> {
+    FromFn(f)
+}
+
+impl<F: FnMut() -> Option<i64>> Input for FromFn<F> {
+    fn read (&mut self) -> Option<i64> {
+        (self.0)()
+    }
+}
+
+pub struct Iter<I> (I);
+
+pub fn iter<I: IntoIterator<Item = i64>> (iterator: I) -> Iter<I::IntoIter> {
+    Iter(iterator.into_iter())
+}
+
+impl<I: Iterator<Item = i64>> Input for Iter<I> {
+    fn read (&mut self) -> Option<i64> {
+        self.0.next()
+    }
+}
+
+impl Input for VecDeque<i64> {
+    fn read (&mut self) -> Option<i64> {
+        self.pop_front()
+    }
+}
+
+// blocks until a value arrives, and stops once every sender is gone
+impl Input for Receiver<i64> {
+    fn read (&mut self) -> Option<i64> {
+        self.recv().ok()
+    }
+}
+
+pub struct Sink<F> (F);
+
+pub fn sink<F: FnMut(i64)> (f: F) -> Sink<F> {
+    Sink(f)
+}
+
+impl<F: FnMut(i64)> Output for Sink<F> {
+    fn write (&mut self, value: i64) {
+        (self.0)(value)
+    }
+}
+
+impl Output for Vec<i64> {
+    fn write (&mut self, value: i64) {
+        self.push(value)
+    }
+}
+
+impl Output for VecDeque<i64> {
+    fn write (&mut self, value: i64) {
+        self.push_back(value)
+    }
+}
+
+// values sent after the receiver hung up are dropped
+impl Output for Sender<i64> {
+    fn write (&mut self, value: i64) {
+        let _ = self.send(value);
+    }
+}
+
+// Reads one number per line, or with `ascii` every byte of a line
+// (including the newline) as a separate value.
+pub struct Stdin {
+    ascii: bool,
+    buffer: VecDeque<i64>
+}
+
+pub fn stdin () -> Stdin {
+    Stdin { ascii: false, buffer: VecDeque::new() }
+}
+
+pub fn ascii_stdin () -> Stdin {
+    Stdin { ascii: true, buffer: VecDeque::new() }
+}
+
+impl Input for Stdin {
+    fn read (&mut self) -> Option<i64> {
+        while self.buffer.is_empty() {
+            let mut line = String::new();
+            if io::stdin().lock().read_line(&mut line).ok()? == 0 {
+                return None
+            }
+
+            if self.ascii {
+                self.buffer.extend(line.bytes().map(|byte| byte as i64));
+            } else if let Ok(value) = line.trim().parse::<i64>() {
+                self.buffer.push_back(value);
+            }
+        }
+
+        self.buffer.pop_front()
+    }
+}
+
+// Prints one number per line, or with `ascii` values below 128 as
+// characters and anything else as a number on its own line.
+pub struct Stdout {
+    ascii: bool
+}
+
+pub fn stdout () -> Stdout {
+    Stdout { ascii: false }
+}
+
+pub fn ascii_stdout () -> Stdout {
+    Stdout { ascii: true }
+}
+
+impl Output for Stdout {
+    fn write (&mut self, value: i64) {
+        let mut stdout = io::stdout();
+        let _ = if self.ascii && (0..128).contains(&value) {
+            write!(stdout, "{}", value as u8 as char)
+        } else {
+            writeln!(stdout, "{}", value)
+        };
+        let _ = stdout.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pauses_without_input () {
+        // outputs the sum of each pair of inputs, forever
+        let mut state = ProgramState::new(&[3, 13, 3, 14, 1, 13, 14, 13, 4, 13, 1105, 1, 0, 0, 0]);
+        let mut output = Vec::new();
+        assert_eq!(run_io(&mut state, &mut iter(vec![1, 2, 3]), &mut output).unwrap(), Status::WaitingForInput);
+        assert_eq!(output, vec![3]);
+        assert_eq!((state.input.clone(), state.input_index), (vec![3], 1));
+
+        let mut queue = VecDeque::from(vec![4]);
+        assert_eq!(run_io(&mut state, &mut queue, &mut output).unwrap(), Status::WaitingForInput);
+        assert_eq!(output, vec![3, 7]);
+    }
+
+    #[test]
+    fn drops_consumed_input () {
+        // echoes its input until it reads 0
+        let mut state = ProgramState::new(&[3, 9, 4, 9, 1005, 9, 0, 99, 0, 0]);
+        let mut output = Vec::new();
+        run_to_halt(&mut state, &mut iter((1..=1000).rev().chain(Some(0))), &mut output).unwrap();
+        assert_eq!(output.len(), 1001);
+        assert_eq!(state.input, vec![0]);
+    }
+
+    #[test]
+    fn runs_to_halt () {
+        let mut state = ProgramState::new(&[3, 5, 4, 5, 99, 0]);
+        let mut output = Vec::new();
+        run_to_halt(&mut state, &mut from_fn(|| Some(8)), &mut sink(|value| output.push(value))).unwrap();
+        assert_eq!(output, vec![8]);
+
+        let mut state = ProgramState::new(&[3, 5, 4, 5, 99, 0]);
+        let error = run_to_halt(&mut state, &mut iter(vec![]), &mut Vec::new()).unwrap_err();
+        assert_eq!((error.tape_index, error.kind), (0, ErrorKind::MissingInput));
+    }
+}
@@ -0,0 +1,328 @@
+mod error;
+mod arithmetic;
+pub mod opcode;
+pub mod disassembler;
+pub mod assembler;
+pub mod debugger;
+pub mod trace;
+pub mod snapshot;
+pub mod io;
+pub mod memory;
+mod operand;
+pub mod decode;
+pub mod profile;
+pub mod cfg;
+pub mod transpile;
+pub mod limits;
+pub mod history;
+pub mod modification;
+pub mod network;
+#[cfg(feature = "bigint")]
+pub mod bigint;
+
+pub use arithmetic::Arithmetic;
+pub use decode::DecodeCache;
+pub use error::{ErrorKind, IntcodeError};
+pub use history::History;
+pub use limits::Limits;
+pub use memory::SparseMemory;
+pub use modification::Modifications;
+pub use trace::{TraceEntry, Tracer};
+
+use operand::{convert_pointer, get_pointer, get_value};
+
+pub(crate) fn get_mode (modes: i64, i: i64) -> i64 {
+    (modes / 10_i64.pow(i as u32)) % 10
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct ProgramState {
+    pub tape: Vec<i64>,
+    pub input: Vec<i64>,
+    pub tape_index: i64,
+    pub input_index: usize,
+    pub relative_base: i64,
+    pub sparse: SparseMemory,
+    pub decoded: DecodeCache,
+    pub limits: Limits,
+    pub arithmetic: Arithmetic,
+    pub history: History,
+    pub modifications: Modifications
+}
+
+impl ProgramState {
+    pub fn new (tape: &[i64]) -> Self {
+        ProgramState {
+            tape: tape.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    // `None` when the instruction pointer is negative
+    pub fn next_opcode (&self) -> Option<i64> {
+        let index = convert_pointer(self.tape_index).ok()?;
+        Some(self.read(index) % 100)
+    }
+
+    pub fn needs_input (&self) -> bool {
+        self.next_opcode() == Some(3) && self.input_index >= self.input.len()
+    }
+
+    pub fn is_done (&self) -> bool {
+        self.next_opcode() == Some(99)
+    }
+}
+
+fn execute (state: &mut ProgramState, tracer: Option<&mut dyn Tracer>) -> Result<Option<i64>, IntcodeError> {
+    let tape_index = state.tape_index;
+    let fault = |opcode, kind| IntcodeError { tape_index, opcode, kind };
+
+    let address = convert_pointer(tape_index).map_err(|kind| fault(None, kind))?;
+    let instruction = state.read(address);
+    let decoded = if address < state.tape.len() {
+        state.decoded.get(address, instruction)
+    } else {
+        decode::decode(instruction)
+    };
+    let opcode = instruction % 100;
+    let decoded = decoded.map_err(|kind| fault(Some(opcode), kind))?;
+    let fault = |kind| fault(Some(opcode), kind);
+
+    limits::check(state).map_err(fault)?;
+    if opcode == 3 && state.input_index >= state.input.len() {
+        return Err(fault(ErrorKind::MissingInput))
+    }
+    let mut next = tape_index.checked_add(decoded.size()).ok_or(fault(ErrorKind::Overflow))?;
+
+    let mut values = [0; 2];
+    for (i, value) in values.iter_mut().enumerate().take(decoded.values as usize) {
+        *value = get_value(state, tape_index + 1 + i as i64, decoded.modes[i]).map_err(fault)?;
+    }
+
+    // addresses of the values read from memory, only needed for tracing
+    let mut reads = Vec::new();
+    if tracer.is_some() {
+        for i in 0..decoded.values as usize {
+            if decoded.modes[i] != 1 {
+                reads.push(get_pointer(state, tape_index + 1 + i as i64, decoded.modes[i]).map_err(fault)?);
+            }
+        }
+    }
+
+    let mut pointer = 0;
+    if decoded.pointers == 1 {
+        let i = decoded.values as usize;
+        pointer = get_pointer(state, tape_index + 1 + i as i64, decoded.modes[i]).map_err(fault)?;
+    }
+
+    // nothing changes until the instruction can no longer fail
+    let relative_base = state.relative_base;
+    let mut output = None;
+    let mut write = None;
+    match opcode {
+        1 => {
+            write = Some(state.arithmetic.add(values[0], values[1]).map_err(fault)?)
+        },
+        2 => {
+            write = Some(state.arithmetic.mul(values[0], values[1]).map_err(fault)?)
+        },
+        3 => {
+            write = Some(state.input[state.input_index]);
+            state.input_index += 1
+        },
+        4 => {
+            output = Some(values[0])
+        },
+        5 => {
+            if values[0] != 0 {
+                next = values[1]
+            }
+        },
+        6 => {
+            if values[0] == 0 {
+                next = values[1]
+            }
+        },
+        7 => {
+            write = Some((values[0] < values[1]) as i64)
+        },
+        8 => {
+            write = Some((values[0] == values[1]) as i64)
+        },
+        9 => {
+            state.relative_base = state.arithmetic.add(state.relative_base, values[0]).map_err(fault)?
+        },
+        99 => {},
+        _ => unreachable!()
+    }
+    state.tape_index = next;
+
+    let write = write.map(|value| (pointer, value));
+    if state.history.is_recording() {
+        state.history.record(history::Change {
+            tape_index,
+            relative_base,
+            write: write.map(|(pointer, _)| (pointer, state.read(pointer))),
+            input: if opcode == 3 { write.map(|(_, value)| value) } else { None },
+            output
+        });
+    }
+    if state.modifications.is_enabled() {
+        modification::record(state, tape_index, decoded.size(), write);
+    }
+    if let Some((pointer, value)) = write {
+        state.write(pointer, value);
+    }
+
+    limits::count(state, opcode == 3 || opcode == 4);
+
+    if let Some(tracer) = tracer {
+        let operands = values.iter().take(decoded.values as usize).copied();
+        let pointers = write.map(|(pointer, _)| pointer as i64);
+        tracer.trace(&TraceEntry {
+            tape_index,
+            instruction,
+            operands: operands.chain(pointers).collect(),
+            reads,
+            write,
+            input: if opcode == 3 { write.map(|(_, value)| value) } else { None },
+            output,
+            relative_base: if opcode == 9 { Some(values[0]) } else { None }
+        });
+    }
+
+    Ok(output)
+}
+
+// On error the instruction pointer is left on the faulting instruction, so
+// that e.g. a missing input can be supplied and the instruction retried.
+pub fn do_instruction (state: &mut ProgramState) -> Result<Option<i64>, IntcodeError> {
+    execute(state, None)
+}
+
+pub fn do_traced_instruction (state: &mut ProgramState, tracer: &mut dyn Tracer) -> Result<Option<i64>, IntcodeError> {
+    execute(state, Some(tracer))
+}
+
+fn reborrow<'a> (tracer: &'a mut Option<&mut dyn Tracer>) -> Option<&'a mut dyn Tracer> {
+    match tracer {
+        Some(tracer) => Some(&mut **tracer),
+        None => None
+    }
+}
+
+fn step_with (state: &mut ProgramState, mut tracer: Option<&mut dyn Tracer>) -> Result<Option<i64>, IntcodeError> {
+    let mut output = None;
+    while output.is_none() && !state.is_done() {
+        output = execute(state, reborrow(&mut tracer))?;
+    }
+    Ok(output)
+}
+
+pub fn step (state: &mut ProgramState) -> Result<Option<i64>, IntcodeError> {
+    step_with(state, None)
+}
+
+pub fn step_traced (state: &mut ProgramState, tracer: &mut dyn Tracer) -> Result<Option<i64>, IntcodeError> {
+    step_with(state, Some(tracer))
+}
+
+pub fn step_input (state: &mut ProgramState, input: Option<i64>) -> Result<Vec<i64>, IntcodeError> {
+    if let Some(value) = input {
+        state.input.push(value);
+    }
+    let mut output = Vec::new();
+    while !state.needs_input() && !state.is_done() {
+        if let Some(value) = do_instruction(state)? {
+            output.push(value);
+        }
+    }
+    Ok(output)
+}
+
+fn run_with (intcode: &[i64], input: &[i64], limits: Limits, mut tracer: Option<&mut dyn Tracer>) -> Result<Vec<i64>, IntcodeError> {
+    let mut output = Vec::new();
+    let mut state = ProgramState {
+        tape: intcode.to_vec(),
+        input: input.to_vec(),
+        limits,
+        ..Default::default()
+    };
+
+    while let Some(step_output) = step_with(&mut state, reborrow(&mut tracer))? {
+        output.push(step_output)
+    }
+
+    Ok(output)
+}
+
+pub fn run (intcode: &[i64], input: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+    run_with(intcode, input, Limits::default(), None)
+}
+
+pub fn run_traced (intcode: &[i64], input: &[i64], tracer: &mut dyn Tracer) -> Result<Vec<i64>, IntcodeError> {
+    run_with(intcode, input, Limits::default(), Some(tracer))
+}
+
+// like `run`, but fails once the budget is used up or the program is caught
+// in a loop, so batch runners do not hang on a busy program
+pub fn run_limited (intcode: &[i64], input: &[i64], limits: Limits) -> Result<Vec<i64>, IntcodeError> {
+    run_with(intcode, input, limits, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error (tape: &[i64], input: &[i64]) -> IntcodeError {
+        run(tape, input).unwrap_err()
+    }
+
+    #[test]
+    fn runs () {
+        // day 5: 999 below 8, 1000 at 8, 1001 above
+        let tape = [
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104,
+            999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20, 1105, 1, 46, 98, 99
+        ];
+        assert_eq!(run(&tape, &[7]).unwrap(), vec![999]);
+        assert_eq!(run(&tape, &[8]).unwrap(), vec![1000]);
+        assert_eq!(run(&tape, &[9]).unwrap(), vec![1001]);
+    }
+
+    #[test]
+    fn grows_memory () {
+        // day 9: a quine that uses memory past its end
+        let tape = [109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99];
+        assert_eq!(run(&tape, &[]).unwrap(), tape.to_vec());
+    }
+
+    #[test]
+    fn unknown_opcode () {
+        assert_eq!(error(&[1101, 1, 1, 5, 42, 0], &[]), IntcodeError { tape_index: 4, opcode: Some(42), kind: ErrorKind::UnknownOpcode });
+    }
+
+    #[test]
+    fn unknown_mode () {
+        assert_eq!(error(&[1, 0, 0, 0, 301, 0, 0, 0, 99], &[]), IntcodeError { tape_index: 4, opcode: Some(1), kind: ErrorKind::UnknownMode(3) });
+    }
+
+    #[test]
+    fn negative_pointers () {
+        assert_eq!(error(&[1, -1, 0, 0, 99], &[]), IntcodeError { tape_index: 0, opcode: Some(1), kind: ErrorKind::NegativePointer(-1) });
+        // a jump to a negative address fails when the instruction is fetched
+        assert_eq!(error(&[1105, 1, -3], &[]), IntcodeError { tape_index: -3, opcode: None, kind: ErrorKind::NegativePointer(-3) });
+    }
+
+    #[test]
+    fn missing_input_can_be_retried () {
+        let mut state = ProgramState::new(&[3, 0, 4, 0, 99]);
+        let error = step(&mut state).unwrap_err();
+        assert_eq!(error, IntcodeError { tape_index: 0, opcode: Some(3), kind: ErrorKind::MissingInput });
+        assert_eq!(error.to_string(), "no input available (opcode 3 at 0)");
+
+        state.input.push(7);
+        assert_eq!(step(&mut state).unwrap(), Some(7));
+    }
+
+}
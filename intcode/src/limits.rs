@@ -0,0 +1,170 @@
+use crate::{ErrorKind, ProgramState, SparseMemory};
+
+// Stops runaway programs. `budget` is the number of instructions left to
+// execute, and `detect_cycles` reports a machine state that repeats exactly
+// with no input or output in between, which can only go on forever.
+#[derive(Default, Clone, Debug)]
+pub struct Limits {
+    pub budget: Option<u64>,
+    pub detect_cycles: bool,
+    cycle: CycleCheck
+}
+
+impl Limits {
+    pub fn new (budget: Option<u64>, detect_cycles: bool) -> Self {
+        Limits { budget, detect_cycles, ..Default::default() }
+    }
+}
+
+// Brent's algorithm: the state is saved after 1, 2, 4, 8... instructions
+// without I/O and every later state is compared against it. Writes since the
+// save add up to `changes`, a sum of hashes that returns to 0 once every cell
+// is back to its saved value, so memory is only compared when the registers
+// agree and `changes` says it may not have changed.
+#[derive(Default, Clone, Debug)]
+struct CycleCheck {
+    saved: Option<Saved>,
+    changes: u64,
+    steps: u64,
+    next_save: u64
+}
+
+#[derive(Clone, Debug)]
+struct Saved {
+    tape_index: i64,
+    relative_base: i64,
+    tape: Vec<i64>,
+    sparse: SparseMemory
+}
+
+impl Saved {
+    fn matches (&self, state: &ProgramState) -> bool {
+        self.tape_index == state.tape_index
+            && self.relative_base == state.relative_base
+            && state.limits.cycle.changes == 0
+            && self.tape == state.tape
+            && self.sparse == state.sparse
+    }
+}
+
+// splitmix64 of the cell
+fn hash (address: usize, value: i64) -> u64 {
+    let mut hash = (address as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ value as u64;
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+// called before each instruction is executed
+pub(crate) fn check (state: &mut ProgramState) -> Result<(), ErrorKind> {
+    if state.limits.budget == Some(0) {
+        return Err(ErrorKind::BudgetExhausted)
+    }
+
+    if state.limits.detect_cycles {
+        let cycle = &state.limits.cycle;
+        if cycle.saved.as_ref().is_some_and(|saved| saved.matches(state)) {
+            return Err(ErrorKind::InfiniteLoop)
+        }
+
+        if cycle.steps >= cycle.next_save {
+            let saved = Saved {
+                tape_index: state.tape_index,
+                relative_base: state.relative_base,
+                tape: state.tape.clone(),
+                sparse: state.sparse.clone()
+            };
+            let cycle = &mut state.limits.cycle;
+            cycle.saved = Some(saved);
+            cycle.changes = 0;
+            cycle.next_save = (cycle.steps * 2).max(1);
+        }
+    }
+
+    Ok(())
+}
+
+// called by `ProgramState::write` before the cell changes from `old`
+pub(crate) fn written (state: &mut ProgramState, address: usize, old: i64, new: i64) {
+    let cycle = &mut state.limits.cycle;
+    cycle.changes = cycle.changes.wrapping_sub(hash(address, old)).wrapping_add(hash(address, new));
+}
+
+// called after each instruction that was executed
+pub(crate) fn count (state: &mut ProgramState, io: bool) {
+    if let Some(budget) = state.limits.budget.as_mut() {
+        *budget -= 1;
+    }
+
+    if state.limits.detect_cycles {
+        if io {
+            state.limits.cycle = CycleCheck::default();
+        } else {
+            state.limits.cycle.steps += 1;
+        }
+    }
+}
+
+// called when an instruction is undone
+pub(crate) fn rewind (state: &mut ProgramState) {
+    if let Some(budget) = state.limits.budget.as_mut() {
+        *budget += 1;
+    }
+    // a state saved before the rewind would be seen again without looping
+    state.limits.cycle = CycleCheck::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembler::assemble;
+    use crate::{step, ErrorKind};
+
+    fn run_limited (source: &str, budget: Option<u64>) -> Result<Option<i64>, ErrorKind> {
+        let mut state = ProgramState::new(&assemble(source).unwrap());
+        state.limits = Limits::new(budget, true);
+        step(&mut state).map_err(|error| error.kind)
+    }
+
+    #[test]
+    fn budget () {
+        let mut state = ProgramState::new(&[1101, 1, 1, 0, 4, 0, 99]);
+        state.limits = Limits::new(Some(1), false);
+        assert_eq!(step(&mut state).unwrap_err().kind, ErrorKind::BudgetExhausted);
+        assert_eq!(state.tape_index, 4);
+
+        state.limits.budget = Some(1);
+        assert_eq!(step(&mut state).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn spinning () {
+        assert_eq!(run_limited("loop: jt #1, #loop", None), Err(ErrorKind::InfiniteLoop));
+    }
+
+    #[test]
+    fn toggling_memory () {
+        // every other pass through the loop is the same state
+        let source = "
+            loop:   eq  flag, #0, flag
+                    jt  #1, #loop
+            flag:   db  0
+        ";
+        assert_eq!(run_limited(source, None), Err(ErrorKind::InfiniteLoop));
+    }
+
+    #[test]
+    fn counting () {
+        let source = "
+            loop:   add  count, #1, count
+                    lt   count, #5000, done
+                    jt   done, #loop
+                    out  count
+                    hlt
+            count:  db   0
+            done:   db   0
+        ";
+        assert_eq!(run_limited(source, Some(100_000)), Ok(Some(5000)));
+        assert_eq!(run_limited(source, Some(100)), Err(ErrorKind::BudgetExhausted));
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use crate::{limits, ProgramState};
+
+pub const PAGE_SIZE: usize = 1024;
+
+// Writes at most this far past the end of the tape grow the tape itself;
+// anything further away is kept in sparse pages instead.
+pub const GROWTH_LIMIT: usize = 64 * PAGE_SIZE;
+
+// Memory beyond the end of the tape, stored as the pages that have actually
+// been written to. Unwritten cells read as 0. The cells are i64, except in
+// `bigint`.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct SparseMemory<T = i64> {
+    pages: BTreeMap<usize, Box<[T]>>
+}
+
+impl<T: Clone + Default + PartialEq> SparseMemory<T> {
+    pub fn get (&self, address: usize) -> T {
+        match self.pages.get(&(address / PAGE_SIZE)) {
+            Some(page) => page[address % PAGE_SIZE].clone(),
+            None => T::default()
+        }
+    }
+
+    pub fn set (&mut self, address: usize, value: T) {
+        let page = address / PAGE_SIZE;
+        if value == T::default() && !self.pages.contains_key(&page) {
+            return
+        }
+
+        let page = self.pages.entry(page).or_insert_with(|| vec![T::default(); PAGE_SIZE].into_boxed_slice());
+        page[address % PAGE_SIZE] = value;
+    }
+
+    pub fn page_count (&self) -> usize {
+        self.pages.len()
+    }
+
+    pub fn is_empty (&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    // non-zero cells in address order
+    pub fn cells (&self) -> impl Iterator<Item = (usize, T)> + '_ {
+        self.pages.iter().flat_map(|(page, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| **value != T::default())
+                .map(move |(i, value)| (page * PAGE_SIZE + i, value.clone()))
+        })
+    }
+
+    // removes and returns the non-zero cells below `end`
+    fn take_below (&mut self, end: usize) -> Vec<(usize, T)> {
+        let last_page = end.div_ceil(PAGE_SIZE);
+        let pages = self.pages.range(..last_page).map(|(&page, _)| page).collect::<Vec<usize>>();
+
+        let mut cells = Vec::new();
+        for page in pages {
+            let values = self.pages.get_mut(&page).unwrap();
+            for (i, value) in values.iter_mut().enumerate() {
+                let address = page * PAGE_SIZE + i;
+                if address < end && *value != T::default() {
+                    cells.push((address, std::mem::take(value)));
+                }
+            }
+
+            if values.iter().all(|value| *value == T::default()) {
+                self.pages.remove(&page);
+            }
+        }
+        cells
+    }
+}
+
+pub(crate) fn load<T: Clone + Default + PartialEq> (tape: &[T], sparse: &SparseMemory<T>, address: usize) -> T {
+    match tape.get(address) {
+        Some(value) => value.clone(),
+        None => sparse.get(address)
+    }
+}
+
+pub(crate) fn store<T: Clone + Default + PartialEq> (tape: &mut Vec<T>, sparse: &mut SparseMemory<T>, address: usize, value: T) {
+    if address >= tape.len() {
+        if address - tape.len() >= GROWTH_LIMIT {
+            return sparse.set(address, value)
+        }
+
+        tape.resize(address + 1, T::default());
+        if !sparse.is_empty() {
+            for (address, value) in sparse.take_below(address + 1) {
+                tape[address] = value;
+            }
+        }
+    }
+
+    tape[address] = value;
+}
+
+impl ProgramState {
+    pub fn read (&self, address: usize) -> i64 {
+        load(&self.tape, &self.sparse, address)
+    }
+
+    pub fn write (&mut self, address: usize, value: i64) {
+        if self.limits.detect_cycles {
+            limits::written(self, address, self.read(address), value);
+        }
+        store(&mut self.tape, &mut self.sparse, address, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn near_writes_grow_the_tape () {
+        let mut state = ProgramState::new(&[99]);
+        state.write(10, 5);
+        assert_eq!(state.tape.len(), 11);
+        assert_eq!(state.read(10), 5);
+        assert_eq!(state.read(5), 0);
+        assert!(state.sparse.is_empty());
+    }
+
+    #[test]
+    fn far_writes_stay_sparse () {
+        let mut state = ProgramState::new(&[99]);
+        state.write(1_000_000_000, 7);
+        state.write(1_000_000_001, 8);
+        assert_eq!(state.tape.len(), 1);
+        assert_eq!(state.sparse.page_count(), 1);
+        assert_eq!(state.read(1_000_000_000), 7);
+        assert_eq!(state.read(1_000_000_001), 8);
+        assert_eq!(state.read(999_999_999), 0);
+
+        // zeroes don't allocate pages
+        state.write(2_000_000_000, 0);
+        assert_eq!(state.sparse.page_count(), 1);
+    }
+
+    #[test]
+    fn growing_takes_over_sparse_cells () {
+        let mut state = ProgramState::new(&[99]);
+        state.write(GROWTH_LIMIT + 10, 3);
+        state.write(GROWTH_LIMIT + 20, 4);
+        assert_eq!(state.sparse.cells().collect::<Vec<(usize, i64)>>(), vec![(GROWTH_LIMIT + 10, 3), (GROWTH_LIMIT + 20, 4)]);
+
+        state.write(GROWTH_LIMIT / 2, 1);
+        state.write(GROWTH_LIMIT + 15, 2);
+        assert_eq!(state.tape.len(), GROWTH_LIMIT + 16);
+        assert_eq!(state.read(GROWTH_LIMIT + 10), 3);
+        assert_eq!(state.sparse.cells().collect::<Vec<(usize, i64)>>(), vec![(GROWTH_LIMIT + 20, 4)]);
+        assert_eq!(state.read(GROWTH_LIMIT + 20), 4);
+    }
+}
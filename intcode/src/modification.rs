@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::ProgramState;
+
+// A write by the instruction at `writer` into a cell that had already been
+// executed, either as an instruction or as one of its parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeWrite {
+    pub writer: i64,
+    pub address: usize,
+    pub old: i64,
+    pub new: i64
+}
+
+impl fmt::Display for CodeWrite {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} wrote to code at {}: {} -> {}", self.writer, self.address, self.old, self.new)
+    }
+}
+
+// Flags self-modifying code. Nothing is tracked by default; `new` starts
+// remembering executed cells and logging writes into them.
+#[derive(Default, Clone, Debug)]
+pub struct Modifications {
+    enabled: bool,
+    executed: HashSet<usize>,
+    pub writes: Vec<CodeWrite>
+}
+
+impl Modifications {
+    pub fn new () -> Self {
+        Modifications { enabled: true, ..Default::default() }
+    }
+
+    pub fn is_enabled (&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_executed (&self, address: usize) -> bool {
+        self.executed.contains(&address)
+    }
+}
+
+// called for each executed instruction, before its write happens
+pub(crate) fn record (state: &mut ProgramState, tape_index: i64, size: i64, write: Option<(usize, i64)>) {
+    let start = tape_index as usize;
+    state.modifications.executed.extend(start..(start + size as usize));
+
+    if let Some((address, new)) = write {
+        if state.modifications.is_executed(address) {
+            let old = state.read(address);
+            state.modifications.writes.push(CodeWrite { writer: tape_index, address, old, new });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembler::assemble;
+    use crate::step;
+
+    #[test]
+    fn flags_writes_to_executed_cells () {
+        let source = "
+            target: out  #cell
+                    jt   done, #halt
+                    add  #4, #0, target
+                    add  #1, #0, done
+                    add  #1, #0, data
+                    jt   #1, #target
+            halt:   hlt
+            done:   db   0
+            data:   db   0
+            cell:   db   42
+        ";
+        let tape = assemble(source).unwrap();
+        let mut state = ProgramState::new(&tape);
+        state.modifications = Modifications::new();
+        while step(&mut state).unwrap().is_some() {}
+
+        // only the write to `target`; `done` and `data` were never executed
+        assert_eq!(state.modifications.writes, vec![CodeWrite { writer: 5, address: 0, old: 104, new: 4 }]);
+        assert!(state.modifications.is_executed(1));
+        assert!(!state.modifications.is_executed(tape.len() - 1));
+    }
+
+    #[test]
+    fn disabled_by_default () {
+        let mut state = ProgramState::new(&[1101, 5, 0, 0, 99]);
+        step(&mut state).unwrap();
+        assert!(state.modifications.writes.is_empty());
+        assert!(!state.modifications.is_executed(0));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{do_instruction, IntcodeError, ProgramState};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Halted,
+    // the machines that are still waiting for input
+    Deadlock(Vec<usize>)
+}
+
+// Machines connected by directed edges. Every output of a machine is logged
+// and copied to the input queue of each machine it is connected to, so
+// chains, rings, fan-out and fan-in are all just edges.
+#[derive(Default, Clone, Debug)]
+pub struct Network {
+    pub machines: Vec<ProgramState>,
+    edges: Vec<Vec<usize>>,
+    outputs: Vec<Vec<i64>>
+}
+
+impl Network {
+    pub fn new () -> Self {
+        Default::default()
+    }
+
+    // adds a machine and returns its id
+    pub fn add (&mut self, state: ProgramState) -> usize {
+        self.machines.push(state);
+        self.edges.push(Vec::new());
+        self.outputs.push(Vec::new());
+        self.machines.len() - 1
+    }
+
+    pub fn connect (&mut self, from: usize, to: usize) {
+        self.edges[from].push(to);
+    }
+
+    // connects each machine to the next
+    pub fn chain (&mut self, machines: &[usize]) {
+        for pair in machines.windows(2) {
+            self.connect(pair[0], pair[1]);
+        }
+    }
+
+    // a chain with the last machine feeding back into the first
+    pub fn ring (&mut self, machines: &[usize]) {
+        self.chain(machines);
+        if let (Some(&first), Some(&last)) = (machines.first(), machines.last()) {
+            self.connect(last, first);
+        }
+    }
+
+    // queues input for a machine
+    pub fn send (&mut self, to: usize, value: i64) {
+        self.machines[to].input.push(value);
+    }
+
+    // everything a machine has output so far
+    pub fn output (&self, machine: usize) -> &[i64] {
+        &self.outputs[machine]
+    }
+
+    // runs one machine until it halts or waits for input; false if it could
+    // not execute anything
+    fn run_machine (&mut self, id: usize) -> Result<bool, IntcodeError> {
+        let mut progress = false;
+        while !self.machines[id].needs_input() && !self.machines[id].is_done() {
+            progress = true;
+            if let Some(value) = do_instruction(&mut self.machines[id])? {
+                self.outputs[id].push(value);
+                for &to in &self.edges[id] {
+                    self.machines[to].input.push(value);
+                }
+            }
+        }
+        Ok(progress)
+    }
+
+    // Runs the machines in turn, each until it halts or runs out of input,
+    // until all of them have halted or none of them can go on.
+    pub fn run (&mut self) -> Result<Outcome, IntcodeError> {
+        loop {
+            let mut progress = false;
+            for id in 0..self.machines.len() {
+                progress |= self.run_machine(id)?;
+            }
+
+            let waiting = (0..self.machines.len()).filter(|&id| !self.machines[id].is_done()).collect::<Vec<usize>>();
+            if waiting.is_empty() {
+                return Ok(Outcome::Halted)
+            }
+            if !progress {
+                return Ok(Outcome::Deadlock(waiting))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    // day 7: the amplifier controller software of the examples
+    const CHAIN: [i64; 17] = [3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0];
+    const FEEDBACK: [i64; 29] = [
+        3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28, 1005, 28, 6, 99, 0, 0, 5
+    ];
+
+    fn amplifiers (tape: &[i64], phases: &[i64]) -> (Network, Vec<usize>) {
+        let mut network = Network::new();
+        let ids = phases.iter().map(|&phase| {
+            let mut state = ProgramState::new(tape);
+            state.input.push(phase);
+            network.add(state)
+        }).collect::<Vec<usize>>();
+        (network, ids)
+    }
+
+    #[test]
+    fn chain () {
+        let (mut network, ids) = amplifiers(&CHAIN, &[4, 3, 2, 1, 0]);
+        network.chain(&ids);
+        network.send(ids[0], 0);
+        assert_eq!(network.run().unwrap(), Outcome::Halted);
+        assert_eq!(network.output(ids[4]), &[43210]);
+    }
+
+    #[test]
+    fn ring () {
+        let (mut network, ids) = amplifiers(&FEEDBACK, &[9, 8, 7, 6, 5]);
+        network.ring(&ids);
+        network.send(ids[0], 0);
+        assert_eq!(network.run().unwrap(), Outcome::Halted);
+        assert_eq!(network.output(ids[4]).last(), Some(&139629729));
+    }
+
+    #[test]
+    fn fan_in () {
+        // two machines output their input doubled into one that adds them
+        let mut network = Network::new();
+        let double = network.add(ProgramState::new(&[3, 9, 1002, 9, 2, 9, 4, 9, 99, 0]));
+        let other = network.add(ProgramState::new(&[3, 9, 1002, 9, 2, 9, 4, 9, 99, 0]));
+        let sum = network.add(ProgramState::new(&[3, 11, 3, 12, 1, 11, 12, 11, 4, 11, 99, 0, 0]));
+        network.connect(double, sum);
+        network.connect(other, sum);
+        network.send(double, 3);
+        network.send(other, 4);
+        assert_eq!(network.run().unwrap(), Outcome::Halted);
+        assert_eq!(network.output(sum), &[14]);
+    }
+
+    #[test]
+    fn deadlock () {
+        let mut network = Network::new();
+        let waiting = network.add(ProgramState::new(&[3, 0, 99]));
+        let halting = network.add(ProgramState::new(&[99]));
+        assert_eq!(network.run().unwrap(), Outcome::Deadlock(vec![waiting]));
+        assert!(network.machines[halting].is_done());
+    }
+
+    #[test]
+    fn errors () {
+        let mut network = Network::new();
+        network.add(ProgramState::new(&[42]));
+        assert_eq!(network.run().unwrap_err().kind, ErrorKind::UnknownOpcode);
+    }
+}
@@ -0,0 +1,61 @@
+// number of (values, pointers)
+pub fn parameters (opcode: i64) -> Option<(i64, i64)> {
+    match opcode {
+        1 | 2 => Some((2, 1)),
+        3 => Some((0, 1)),
+        4 => Some((1, 0)),
+        5 | 6 => Some((2, 0)),
+        7 | 8 => Some((2, 1)),
+        9 => Some((1, 0)),
+        99 => Some((0, 0)),
+        _ => None
+    }
+}
+
+pub fn mnemonic (opcode: i64) -> Option<&'static str> {
+    match opcode {
+        1 => Some("add"),
+        2 => Some("mul"),
+        3 => Some("in"),
+        4 => Some("out"),
+        5 => Some("jt"),
+        6 => Some("jf"),
+        7 => Some("lt"),
+        8 => Some("eq"),
+        9 => Some("arb"),
+        99 => Some("hlt"),
+        _ => None
+    }
+}
+
+pub fn from_mnemonic (mnemonic: &str) -> Option<i64> {
+    match mnemonic {
+        "add" => Some(1),
+        "mul" => Some(2),
+        "in" => Some(3),
+        "out" => Some(4),
+        "jt" => Some(5),
+        "jf" => Some(6),
+        "lt" => Some(7),
+        "eq" => Some(8),
+        "arb" => Some(9),
+        "hlt" => Some(99),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_agree () {
+        for opcode in 0..100 {
+            assert_eq!(parameters(opcode).is_some(), mnemonic(opcode).is_some());
+            if let Some(mnemonic) = mnemonic(opcode) {
+                assert_eq!(from_mnemonic(mnemonic), Some(opcode));
+            }
+        }
+        assert_eq!(from_mnemonic("jmp"), None);
+    }
+}
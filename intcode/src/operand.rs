@@ -0,0 +1,53 @@
+use crate::{ErrorKind, ProgramState};
+
+// What resolving operands needs from a machine. `ProgramState` holds i64
+// cells; `bigint::BigProgramState` holds big integers, which have to fit in
+// an i64 to be used as an address.
+pub(crate) trait Cells {
+    type Cell;
+
+    fn cell (&self, address: usize) -> Self::Cell;
+    fn address (cell: &Self::Cell) -> Result<i64, ErrorKind>;
+    fn relative_base (&self) -> i64;
+}
+
+impl Cells for ProgramState {
+    type Cell = i64;
+
+    fn cell (&self, address: usize) -> i64 {
+        self.read(address)
+    }
+
+    fn address (cell: &i64) -> Result<i64, ErrorKind> {
+        Ok(*cell)
+    }
+
+    fn relative_base (&self) -> i64 {
+        self.relative_base
+    }
+}
+
+pub(crate) fn convert_pointer (pointer: i64) -> Result<usize, ErrorKind> {
+    if pointer >= 0 {
+        Ok(pointer as usize)
+    } else {
+        Err(ErrorKind::NegativePointer(pointer))
+    }
+}
+
+// addresses never wrap around, whatever the arithmetic policy
+pub(crate) fn get_pointer<M: Cells> (machine: &M, pointer: i64, mode: u8) -> Result<usize, ErrorKind> {
+    let pointer_value = M::address(&machine.cell(convert_pointer(pointer)?))?;
+    match mode {
+        0 | 1 => convert_pointer(pointer_value),
+        2 => convert_pointer(pointer_value.checked_add(machine.relative_base()).ok_or(ErrorKind::Overflow)?),
+        _ => unreachable!()
+    }
+}
+
+pub(crate) fn get_value<M: Cells> (machine: &M, pointer: i64, mode: u8) -> Result<M::Cell, ErrorKind> {
+    match mode {
+        1 => Ok(machine.cell(convert_pointer(pointer)?)),
+        _ => Ok(machine.cell(get_pointer(machine, pointer, mode)?))
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::hash::Hash;
+
+use crate::disassembler;
+use crate::opcode;
+use crate::trace::{TraceEntry, Tracer};
+
+// Counts executions per address and per instruction kind (opcode and
+// parameter modes), and memory reads and writes per cell.
+#[derive(Default, Debug, Clone)]
+pub struct Profiler {
+    pub instructions: u64,
+    pub executions: HashMap<i64, u64>,
+    pub kinds: HashMap<i64, u64>,
+    pub reads: HashMap<usize, u64>,
+    pub writes: HashMap<usize, u64>
+}
+
+impl Tracer for Profiler {
+    fn trace (&mut self, entry: &TraceEntry) {
+        self.instructions += 1;
+        *self.executions.entry(entry.tape_index).or_insert(0) += 1;
+        *self.kinds.entry(entry.instruction).or_insert(0) += 1;
+
+        for &address in &entry.reads {
+            *self.reads.entry(address).or_insert(0) += 1;
+        }
+        if let Some((address, _)) = entry.write {
+            *self.writes.entry(address).or_insert(0) += 1;
+        }
+    }
+}
+
+// highest counts first, ties in key order
+fn sorted<K: Copy + Ord + Hash> (counts: &HashMap<K, u64>, limit: usize) -> Vec<(K, u64)> {
+    let mut counts = counts.iter().map(|(&key, &count)| (key, count)).collect::<Vec<(K, u64)>>();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    counts.truncate(limit);
+    counts
+}
+
+// `add [pos, imm, rel]` for instruction 21001
+fn describe_kind (instruction: i64) -> String {
+    let opcode = instruction % 100;
+    let mnemonic = opcode::mnemonic(opcode).unwrap_or("?");
+    let (values, pointers) = opcode::parameters(opcode).unwrap_or((0, 0));
+
+    let modes = (0..(values + pointers))
+        .map(|i| match crate::get_mode(instruction / 100, i) {
+            0 => "pos",
+            1 => "imm",
+            2 => "rel",
+            _ => "?"
+        })
+        .collect::<Vec<&str>>();
+
+    if modes.is_empty() {
+        mnemonic.to_string()
+    } else {
+        format!("{} [{}]", mnemonic, modes.join(", "))
+    }
+}
+
+impl Profiler {
+    fn percentage (&self, count: u64) -> f64 {
+        100.0 * count as f64 / self.instructions.max(1) as f64
+    }
+
+    // The `limit` most frequent entries of each table. With the tape, hot
+    // addresses are shown with their disassembly.
+    pub fn report (&self, tape: Option<&[i64]>, limit: usize) -> String {
+        let mut report = String::new();
+        writeln!(report, "{} instructions executed", self.instructions).unwrap();
+
+        writeln!(report, "\nhot addresses").unwrap();
+        for (address, count) in sorted(&self.executions, limit) {
+            let instruction = tape
+                .filter(|_| address >= 0)
+                .and_then(|tape| disassembler::decode(tape, address as usize))
+                .map(|instruction| instruction.to_string())
+                .unwrap_or_else(|| format!("{:>5}:", address));
+            writeln!(report, "{:>12} {:>6.2}%  {}", count, self.percentage(count), instruction).unwrap();
+        }
+
+        writeln!(report, "\ninstructions").unwrap();
+        for (instruction, count) in sorted(&self.kinds, limit) {
+            writeln!(report, "{:>12} {:>6.2}%  {}", count, self.percentage(count), describe_kind(instruction)).unwrap();
+        }
+
+        writeln!(report, "\nmemory reads").unwrap();
+        for (address, count) in sorted(&self.reads, limit) {
+            writeln!(report, "{:>12}  {}", count, address).unwrap();
+        }
+
+        writeln!(report, "\nmemory writes").unwrap();
+        for (address, count) in sorted(&self.writes, limit) {
+            writeln!(report, "{:>12}  {}", count, address).unwrap();
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_traced;
+
+    #[test]
+    fn counts () {
+        // counts cell 9 down from 3
+        let tape = [1001, 9, -1, 9, 1005, 9, 0, 99, 0, 3];
+        let mut profiler = Profiler::default();
+        run_traced(&tape, &[], &mut profiler).unwrap();
+
+        assert_eq!(profiler.instructions, 6);
+        assert_eq!(sorted(&profiler.executions, 10), vec![(0, 3), (4, 3)]);
+        assert_eq!(profiler.writes.get(&9), Some(&3));
+        assert_eq!(profiler.reads.get(&9), Some(&6));
+        assert_eq!(describe_kind(1001), "add [pos, imm, pos]");
+        assert_eq!(describe_kind(2005), "jt [pos, rel]");
+    }
+
+    #[test]
+    fn reports_hot_addresses () {
+        let tape = [1001, 9, -1, 9, 1005, 9, 0, 99, 0, 3];
+        let mut profiler = Profiler::default();
+        run_traced(&tape, &[], &mut profiler).unwrap();
+
+        let report = profiler.report(Some(&tape), 1);
+        assert!(report.starts_with("6 instructions executed\n\nhot addresses\n"));
+        assert!(report.contains("           3  50.00%      0: add 9, #-1, 9\n"));
+    }
+}
@@ -0,0 +1,252 @@
+use std::error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::{Arithmetic, Limits, ProgramState, SparseMemory};
+
+const MAGIC: &str = "intcode-snapshot";
+pub const VERSION: u32 = 3;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    NotASnapshot,
+    UnsupportedVersion(u32),
+    InvalidLine(usize),
+    MissingField(&'static str)
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(error) => write!(f, "{}", error),
+            SnapshotError::NotASnapshot => write!(f, "not an intcode snapshot"),
+            SnapshotError::UnsupportedVersion(version) => write!(f, "unsupported snapshot version {} (expected at most {})", version, VERSION),
+            SnapshotError::InvalidLine(line) => write!(f, "invalid snapshot line {}", line),
+            SnapshotError::MissingField(field) => write!(f, "snapshot is missing {}", field)
+        }
+    }
+}
+
+impl error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from (error: io::Error) -> Self {
+        SnapshotError::Io(error)
+    }
+}
+
+fn join (values: &[i64]) -> String {
+    values.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(",")
+}
+
+fn parse_list (string: &str) -> Option<Vec<i64>> {
+    if string.is_empty() {
+        return Some(Vec::new())
+    }
+    string.split(',').map(|value| value.parse::<i64>().ok()).collect()
+}
+
+fn join_cells (memory: &SparseMemory) -> String {
+    memory.cells().map(|(address, value)| format!("{}:{}", address, value)).collect::<Vec<String>>().join(",")
+}
+
+fn parse_cells (string: &str) -> Option<SparseMemory> {
+    let mut memory = SparseMemory::default();
+    for cell in string.split(',').filter(|cell| !cell.is_empty()) {
+        let (address, value) = cell.split_once(':')?;
+        memory.set(address.parse::<usize>().ok()?, value.parse::<i64>().ok()?);
+    }
+    Some(memory)
+}
+
+fn arithmetic_name (arithmetic: Arithmetic) -> &'static str {
+    match arithmetic {
+        Arithmetic::Checked => "checked",
+        Arithmetic::Wrapping => "wrapping"
+    }
+}
+
+fn parse_arithmetic (string: &str) -> Option<Arithmetic> {
+    match string {
+        "checked" => Some(Arithmetic::Checked),
+        "wrapping" => Some(Arithmetic::Wrapping),
+        _ => None
+    }
+}
+
+// no budget is written as an empty value
+fn parse_budget (string: &str) -> Option<Option<u64>> {
+    match string {
+        "" => Some(None),
+        budget => budget.parse::<u64>().ok().map(Some)
+    }
+}
+
+// The format is line based text: a `intcode-snapshot <version>` header
+// followed by one `<field> <value>` line per field of the machine. Version 2
+// added the `sparse` field (`address:value` pairs), which version 1 lacks.
+// Version 3 added `arithmetic`, `budget` (the instructions left) and
+// `detect_cycles`; older snapshots restore with the defaults.
+impl ProgramState {
+    pub fn write_snapshot<W: Write> (&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{} {}", MAGIC, VERSION)?;
+        writeln!(writer, "tape_index {}", self.tape_index)?;
+        writeln!(writer, "relative_base {}", self.relative_base)?;
+        writeln!(writer, "input_index {}", self.input_index)?;
+        writeln!(writer, "input {}", join(&self.input))?;
+        writeln!(writer, "tape {}", join(&self.tape))?;
+        writeln!(writer, "sparse {}", join_cells(&self.sparse))?;
+        writeln!(writer, "arithmetic {}", arithmetic_name(self.arithmetic))?;
+        writeln!(writer, "budget {}", self.limits.budget.map_or(String::new(), |budget| budget.to_string()))?;
+        writeln!(writer, "detect_cycles {}", self.limits.detect_cycles)?;
+        writer.flush()
+    }
+
+    pub fn read_snapshot<R: BufRead> (reader: R) -> Result<Self, SnapshotError> {
+        let mut lines = reader.lines();
+
+        let header = lines.next().ok_or(SnapshotError::NotASnapshot)??;
+        let version = match header.split_once(' ') {
+            Some((MAGIC, version)) => version.parse::<u32>().map_err(|_| SnapshotError::NotASnapshot)?,
+            _ => return Err(SnapshotError::NotASnapshot)
+        };
+        if version == 0 || version > VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version))
+        }
+
+        let mut tape_index = None;
+        let mut relative_base = None;
+        let mut input_index = None;
+        let mut input = None;
+        let mut tape = None;
+        let mut sparse = None;
+        let mut arithmetic = None;
+        let mut budget = None;
+        let mut detect_cycles = None;
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+            let invalid = SnapshotError::InvalidLine(i + 2);
+            let (field, value) = line.split_once(' ').unwrap_or((&line, ""));
+
+            match field {
+                "tape_index" => tape_index = Some(value.parse::<i64>().map_err(|_| invalid)?),
+                "relative_base" => relative_base = Some(value.parse::<i64>().map_err(|_| invalid)?),
+                "input_index" => input_index = Some(value.parse::<usize>().map_err(|_| invalid)?),
+                "input" => input = Some(parse_list(value).ok_or(invalid)?),
+                "tape" => tape = Some(parse_list(value).ok_or(invalid)?),
+                "sparse" if version >= 2 => sparse = Some(parse_cells(value).ok_or(invalid)?),
+                "arithmetic" if version >= 3 => arithmetic = Some(parse_arithmetic(value).ok_or(invalid)?),
+                "budget" if version >= 3 => budget = Some(parse_budget(value).ok_or(invalid)?),
+                "detect_cycles" if version >= 3 => detect_cycles = Some(value.parse::<bool>().map_err(|_| invalid)?),
+                "" => {},
+                _ => return Err(invalid)
+            }
+        }
+
+        Ok(ProgramState {
+            tape: tape.ok_or(SnapshotError::MissingField("tape"))?,
+            input: input.ok_or(SnapshotError::MissingField("input"))?,
+            tape_index: tape_index.ok_or(SnapshotError::MissingField("tape_index"))?,
+            input_index: input_index.ok_or(SnapshotError::MissingField("input_index"))?,
+            relative_base: relative_base.ok_or(SnapshotError::MissingField("relative_base"))?,
+            sparse: match (version, sparse) {
+                (1, _) => SparseMemory::default(),
+                (_, sparse) => sparse.ok_or(SnapshotError::MissingField("sparse"))?
+            },
+            arithmetic: match version {
+                1 | 2 => Arithmetic::default(),
+                _ => arithmetic.ok_or(SnapshotError::MissingField("arithmetic"))?
+            },
+            limits: match version {
+                1 | 2 => Limits::default(),
+                _ => Limits::new(
+                    budget.ok_or(SnapshotError::MissingField("budget"))?,
+                    detect_cycles.ok_or(SnapshotError::MissingField("detect_cycles"))?
+                )
+            },
+            ..Default::default()
+        })
+    }
+
+    pub fn save<P: AsRef<Path>> (&self, path: P) -> io::Result<()> {
+        self.write_snapshot(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn restore<P: AsRef<Path>> (path: P) -> Result<Self, SnapshotError> {
+        ProgramState::read_snapshot(BufReader::new(File::open(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip (state: &ProgramState) -> ProgramState {
+        let mut snapshot = Vec::new();
+        state.write_snapshot(&mut snapshot).unwrap();
+        ProgramState::read_snapshot(&snapshot[..]).unwrap()
+    }
+
+    #[test]
+    fn keeps_machine () {
+        let mut state = ProgramState::new(&[3, 0, 4, 0, 99]);
+        state.input = vec![7, 8];
+        state.input_index = 1;
+        state.tape_index = 2;
+        state.relative_base = -4;
+        state.sparse.set(100_000, 5);
+
+        let restored = round_trip(&state);
+        assert_eq!(restored.tape, state.tape);
+        assert_eq!(restored.input, state.input);
+        assert_eq!(restored.input_index, 1);
+        assert_eq!(restored.tape_index, 2);
+        assert_eq!(restored.relative_base, -4);
+        assert_eq!(restored.sparse, state.sparse);
+    }
+
+    #[test]
+    fn keeps_arithmetic_and_limits () {
+        let mut state = ProgramState::new(&[99]);
+        state.arithmetic = Arithmetic::Wrapping;
+        state.limits = Limits::new(Some(42), true);
+
+        let restored = round_trip(&state);
+        assert_eq!(restored.arithmetic, Arithmetic::Wrapping);
+        assert_eq!(restored.limits.budget, Some(42));
+        assert!(restored.limits.detect_cycles);
+
+        let restored = round_trip(&ProgramState::new(&[99]));
+        assert_eq!(restored.limits.budget, None);
+        assert!(!restored.limits.detect_cycles);
+    }
+
+    #[test]
+    fn reads_older_versions () {
+        let v1 = "intcode-snapshot 1\ntape_index 0\nrelative_base 0\ninput_index 0\ninput 1\ntape 3,0,99\n";
+        let state = ProgramState::read_snapshot(v1.as_bytes()).unwrap();
+        assert_eq!(state.tape, vec![3, 0, 99]);
+        assert!(state.sparse.is_empty());
+        assert_eq!(state.arithmetic, Arithmetic::Checked);
+        assert_eq!(state.limits.budget, None);
+
+        let v2 = "intcode-snapshot 2\ntape_index 0\nrelative_base 0\ninput_index 0\ninput \ntape 99\nsparse 70000:3\n";
+        let state = ProgramState::read_snapshot(v2.as_bytes()).unwrap();
+        assert_eq!(state.sparse.get(70_000), 3);
+        assert_eq!(state.arithmetic, Arithmetic::Checked);
+
+        // v2 snapshots can't have v3 fields
+        let v2 = format!("{}arithmetic wrapping\n", v2);
+        assert!(matches!(ProgramState::read_snapshot(v2.as_bytes()), Err(SnapshotError::InvalidLine(8))));
+    }
+
+    #[test]
+    fn rejects_newer_versions () {
+        let snapshot = format!("intcode-snapshot {}\n", VERSION + 1);
+        assert!(matches!(ProgramState::read_snapshot(snapshot.as_bytes()), Err(SnapshotError::UnsupportedVersion(_))));
+    }
+}
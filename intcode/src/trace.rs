@@ -0,0 +1,115 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::opcode;
+
+// One executed instruction. `operands` holds the resolved parameter values
+// followed by the address written to, if any, and `reads` the addresses the
+// values were read from (immediate values are not read from memory).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub tape_index: i64,
+    pub instruction: i64,
+    pub operands: Vec<i64>,
+    pub reads: Vec<usize>,
+    pub write: Option<(usize, i64)>,
+    pub input: Option<i64>,
+    pub output: Option<i64>,
+    pub relative_base: Option<i64>
+}
+
+impl TraceEntry {
+    pub fn opcode (&self) -> i64 {
+        self.instruction % 100
+    }
+}
+
+// `ip mnemonic operands [address]=value <input >output rb+change`, leaving
+// out whatever did not happen, so two traces can be compared with `diff`
+impl fmt::Display for TraceEntry {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operands = self.operands.iter().map(|operand| operand.to_string()).collect::<Vec<String>>();
+        write!(f, "{} {}", self.tape_index, opcode::mnemonic(self.opcode()).unwrap_or("?"))?;
+
+        if !operands.is_empty() {
+            write!(f, " {}", operands.join(","))?;
+        }
+        if let Some((address, value)) = self.write {
+            write!(f, " [{}]={}", address, value)?;
+        }
+        if let Some(input) = self.input {
+            write!(f, " <{}", input)?;
+        }
+        if let Some(output) = self.output {
+            write!(f, " >{}", output)?;
+        }
+        if let Some(change) = self.relative_base {
+            write!(f, " rb{:+}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub trait Tracer {
+    fn trace (&mut self, entry: &TraceEntry);
+}
+
+impl Tracer for Vec<TraceEntry> {
+    fn trace (&mut self, entry: &TraceEntry) {
+        self.push(entry.clone());
+    }
+}
+
+// Writes one line per entry. Tracing cannot fail halfway through a run, so
+// the first write error is kept and returned by `finish`.
+pub struct TraceWriter<W: Write> {
+    writer: W,
+    error: Option<io::Error>
+}
+
+impl<W: Write> TraceWriter<W> {
+    pub fn new (writer: W) -> Self {
+        TraceWriter { writer, error: None }
+    }
+
+    pub fn finish (mut self) -> io::Result<W> {
+        match self.error {
+            Some(error) => Err(error),
+            None => self.writer.flush().map(|_| self.writer)
+        }
+    }
+}
+
+impl<W: Write> Tracer for TraceWriter<W> {
+    fn trace (&mut self, entry: &TraceEntry) {
+        if self.error.is_none() {
+            if let Err(error) = writeln!(self.writer, "{}", entry) {
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_traced;
+
+    #[test]
+    fn records_every_instruction () {
+        // the halt itself is not executed
+        let mut entries = Vec::new();
+        assert_eq!(run_traced(&[3, 9, 109, 2, 22101, 1, 7, 8, 99, 0], &[4], &mut entries).unwrap(), vec![]);
+        let lines = entries.iter().map(|entry| entry.to_string()).collect::<Vec<String>>();
+        assert_eq!(lines, vec!["0 in 9 [9]=4 <4", "2 arb 2 rb+2", "4 add 1,4,10 [10]=5"]);
+        assert_eq!(entries[2].reads, vec![9]);
+    }
+
+    #[test]
+    fn writes_lines () {
+        let mut writer = TraceWriter::new(Vec::new());
+        run_traced(&[104, 7, 99], &[], &mut writer).unwrap();
+        assert_eq!(String::from_utf8(writer.finish().unwrap()).unwrap(), "0 out 7 >7\n");
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use crate::disassembler::{self, Instruction, Mode, Operand};
+
+// Code shared by every generated program. `store` reports writes that change
+// the program's own code, after which the compiled instructions can no longer
+// be trusted and `resume` finishes the run on the interpreter.
+const RUNTIME: &str = "
+#[allow(dead_code)]
+fn address (pointer: i64) -> Result<usize, ErrorKind> {
+    if pointer >= 0 {
+        Ok(pointer as usize)
+    } else {
+        Err(ErrorKind::NegativePointer(pointer))
+    }
+}
+
+#[allow(dead_code)]
+fn relative (state: &ProgramState, offset: i64) -> Result<usize, ErrorKind> {
+    address(offset.checked_add(state.relative_base).ok_or(ErrorKind::Overflow)?)
+}
+
+#[allow(dead_code)]
+fn store (state: &mut ProgramState, address: usize, value: i64) -> bool {
+    let modified = address < CODE.len() && CODE[address] && state.tape[address] != value;
+    state.write(address, value);
+    modified
+}
+
+fn resume (mut state: ProgramState, mut output: Vec<i64>) -> Result<Vec<i64>, IntcodeError> {
+    while let Some(value) = intcode::step(&mut state)? {
+        output.push(value)
+    }
+    Ok(output)
+}
+";
+
+fn value (operand: &Operand) -> String {
+    match operand.mode {
+        Mode::Immediate => operand.value.to_string(),
+        _ => format!("state.read({})", pointer(operand))
+    }
+}
+
+fn pointer (operand: &Operand) -> String {
+    match operand.mode {
+        Mode::Relative => format!("relative(&state, {}).map_err(fault)?", operand.value),
+        _ if operand.value >= 0 => operand.value.to_string(),
+        _ => format!("address({}).map_err(fault)?", operand.value)
+    }
+}
+
+// One arm of the dispatch `match`, doing what the interpreter does for the
+// instruction in the same order, so that errors come out identical. Like
+// `intcode::run`, arithmetic is checked.
+fn arm (code: &mut String, instruction: &Instruction) {
+    let address = instruction.address;
+    let operands = &instruction.operands;
+    writeln!(code, "            // {}", instruction.to_string().trim_start()).unwrap();
+
+    if instruction.opcode == 99 {
+        writeln!(code, "            {} => return Ok(output),", address).unwrap();
+        return
+    }
+
+    writeln!(code, "            {} => {{", address).unwrap();
+    writeln!(code, "                let fault = |kind| IntcodeError {{ tape_index: {}, opcode: Some({}), kind }};", address, instruction.opcode).unwrap();
+    if instruction.opcode == 3 {
+        writeln!(code, "                if state.input_index >= state.input.len() {{").unwrap();
+        writeln!(code, "                    return Err(fault(ErrorKind::MissingInput))").unwrap();
+        writeln!(code, "                }}").unwrap();
+    }
+
+    let (values, pointers) = crate::opcode::parameters(instruction.opcode).unwrap();
+    for (name, operand) in ["a", "b"].iter().zip(&operands[..values as usize]) {
+        writeln!(code, "                let {}: i64 = {};", name, value(operand)).unwrap();
+    }
+    if pointers == 1 {
+        writeln!(code, "                let p = {};", pointer(&operands[values as usize])).unwrap();
+    }
+    match instruction.opcode {
+        1 => writeln!(code, "                let value = a.checked_add(b).ok_or(fault(ErrorKind::Overflow))?;").unwrap(),
+        2 => writeln!(code, "                let value = a.checked_mul(b).ok_or(fault(ErrorKind::Overflow))?;").unwrap(),
+        9 => writeln!(code, "                let value = state.relative_base.checked_add(a).ok_or(fault(ErrorKind::Overflow))?;").unwrap(),
+        _ => {}
+    }
+    writeln!(code, "                state.tape_index = {};", address + instruction.size()).unwrap();
+
+    let result = match instruction.opcode {
+        1 | 2 => "value",
+        3 => {
+            writeln!(code, "                let value = state.input[state.input_index];").unwrap();
+            writeln!(code, "                state.input_index += 1;").unwrap();
+            "value"
+        },
+        4 => {
+            writeln!(code, "                output.push(a);").unwrap();
+            ""
+        },
+        5 | 6 => {
+            let condition = if instruction.opcode == 5 { "a != 0" } else { "a == 0" };
+            writeln!(code, "                if {} {{", condition).unwrap();
+            writeln!(code, "                    state.tape_index = b").unwrap();
+            writeln!(code, "                }}").unwrap();
+            ""
+        },
+        7 => "(a < b) as i64",
+        8 => "(a == b) as i64",
+        9 => {
+            writeln!(code, "                state.relative_base = value;").unwrap();
+            ""
+        },
+        _ => unreachable!()
+    };
+
+    if !result.is_empty() {
+        writeln!(code, "                if store(&mut state, p, {}) {{", result).unwrap();
+        writeln!(code, "                    return resume(state, output)").unwrap();
+        writeln!(code, "                }}").unwrap();
+    }
+    writeln!(code, "            }},").unwrap();
+}
+
+fn table<T: ToString> (code: &mut String, name: &str, kind: &str, values: &[T]) {
+    writeln!(code, "pub const {}: [{}; {}] = [", name, kind, values.len()).unwrap();
+    for chunk in values.chunks(16) {
+        let chunk = chunk.iter().map(|value| value.to_string()).collect::<Vec<String>>();
+        writeln!(code, "    {},", chunk.join(", ")).unwrap();
+    }
+    writeln!(code, "];").unwrap();
+}
+
+// Rust source for a module with `pub fn run (input: &[i64])`, which behaves
+// like `intcode::run(&TAPE, input)`. Instructions found by the disassembler
+// are compiled into the arms of a `match` on the instruction pointer; jumps
+// anywhere else, and any write that changes the code, hand the rest of the
+// run over to the interpreter.
+pub fn transpile (tape: &[i64]) -> String {
+    let code = disassembler::find_code(tape);
+    let covered = code
+        .iter()
+        .flat_map(|instruction| instruction.address..(instruction.address + instruction.size()))
+        .collect::<BTreeSet<usize>>();
+    let covered = (0..tape.len()).map(|address| covered.contains(&address)).collect::<Vec<bool>>();
+
+    let mut source = String::new();
+    writeln!(source, "// Generated by intcode's `transpile`, do not edit.").unwrap();
+    writeln!(source, "use intcode::{{ErrorKind, IntcodeError, ProgramState}};\n").unwrap();
+    table(&mut source, "TAPE", "i64", tape);
+    writeln!(source).unwrap();
+    table(&mut source, "CODE", "bool", &covered);
+    source.push_str(RUNTIME);
+
+    writeln!(source, "\n#[allow(unused_mut, unused_variables, unreachable_code, clippy::all)]").unwrap();
+    writeln!(source, "pub fn run (input: &[i64]) -> Result<Vec<i64>, IntcodeError> {{").unwrap();
+    writeln!(source, "    let mut state = ProgramState::new(&TAPE);").unwrap();
+    writeln!(source, "    state.input = input.to_vec();").unwrap();
+    writeln!(source, "    let mut output = Vec::new();\n").unwrap();
+    writeln!(source, "    loop {{").unwrap();
+    writeln!(source, "        match state.tape_index {{").unwrap();
+    for instruction in &code {
+        arm(&mut source, instruction);
+    }
+    writeln!(source, "            _ => return resume(state, output)").unwrap();
+    writeln!(source, "        }}").unwrap();
+    writeln!(source, "    }}").unwrap();
+    writeln!(source, "}}").unwrap();
+
+    source
+}
@@ -0,0 +1,51 @@
+// The programs in `transpiled/` were generated with
+//
+//     cargo run --bin transpile <tape> > tests/transpiled/<name>.rs
+//
+// and have to be regenerated whenever the transpiler's output changes.
+use intcode::transpile::transpile;
+
+#[path = "transpiled/compare.rs"]
+mod compare;
+#[path = "transpiled/overflow.rs"]
+mod overflow;
+#[path = "transpiled/patch.rs"]
+mod patch;
+#[path = "transpiled/quine.rs"]
+mod quine;
+
+#[test]
+fn up_to_date () {
+    assert_eq!(transpile(&compare::TAPE), include_str!("transpiled/compare.rs"));
+    assert_eq!(transpile(&overflow::TAPE), include_str!("transpiled/overflow.rs"));
+    assert_eq!(transpile(&patch::TAPE), include_str!("transpiled/patch.rs"));
+    assert_eq!(transpile(&quine::TAPE), include_str!("transpiled/quine.rs"));
+}
+
+#[test]
+fn jumps_and_io () {
+    for input in 0..16 {
+        assert_eq!(compare::run(&[input]), intcode::run(&compare::TAPE, &[input]));
+    }
+    // the same missing input error
+    assert_eq!(compare::run(&[]), intcode::run(&compare::TAPE, &[]));
+}
+
+#[test]
+fn relative_base_and_growth () {
+    assert_eq!(quine::run(&[]).unwrap(), quine::TAPE.to_vec());
+}
+
+#[test]
+fn self_modifying_code () {
+    // rewrites its first instruction and jumps back to it, which the
+    // interpreter has to finish
+    assert_eq!(patch::run(&[]), intcode::run(&patch::TAPE, &[]));
+    assert_eq!(patch::run(&[]).unwrap(), vec![18, 42]);
+}
+
+#[test]
+fn errors () {
+    assert_eq!(overflow::run(&[]), intcode::run(&overflow::TAPE, &[]));
+    assert!(overflow::run(&[]).is_err());
+}
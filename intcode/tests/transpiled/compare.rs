@@ -0,0 +1,198 @@
+// Generated by intcode's `transpile`, do not edit.
+use intcode::{ErrorKind, IntcodeError, ProgramState};
+
+pub const TAPE: [i64; 47] = [
+    3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31,
+    1106, 0, 36, 98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104,
+    999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20, 1105, 1, 46, 98, 99,
+];
+
+pub const CODE: [bool; 47] = [
+    true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+    true, true, true, false, false, false, true, true, true, true, true, true, true, true, true, true,
+    true, true, true, true, true, true, true, true, true, true, true, true, true, false, true,
+];
+
+#[allow(dead_code)]
+fn address (pointer: i64) -> Result<usize, ErrorKind> {
+    if pointer >= 0 {
+        Ok(pointer as usize)
+    } else {
+        Err(ErrorKind::NegativePointer(pointer))
+    }
+}
+
+#[allow(dead_code)]
+fn relative (state: &ProgramState, offset: i64) -> Result<usize, ErrorKind> {
+    address(offset.checked_add(state.relative_base).ok_or(ErrorKind::Overflow)?)
+}
+
+#[allow(dead_code)]
+fn store (state: &mut ProgramState, address: usize, value: i64) -> bool {
+    let modified = address < CODE.len() && CODE[address] && state.tape[address] != value;
+    state.write(address, value);
+    modified
+}
+
+fn resume (mut state: ProgramState, mut output: Vec<i64>) -> Result<Vec<i64>, IntcodeError> {
+    while let Some(value) = intcode::step(&mut state)? {
+        output.push(value)
+    }
+    Ok(output)
+}
+
+#[allow(unused_mut, unused_variables, unreachable_code, clippy::all)]
+pub fn run (input: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+    let mut state = ProgramState::new(&TAPE);
+    state.input = input.to_vec();
+    let mut output = Vec::new();
+
+    loop {
+        match state.tape_index {
+            // 0: in  21
+            0 => {
+                let fault = |kind| IntcodeError { tape_index: 0, opcode: Some(3), kind };
+                if state.input_index >= state.input.len() {
+                    return Err(fault(ErrorKind::MissingInput))
+                }
+                let p = 21;
+                state.tape_index = 2;
+                let value = state.input[state.input_index];
+                state.input_index += 1;
+                if store(&mut state, p, value) {
+                    return resume(state, output)
+                }
+            },
+            // 2: eq  21, #8, 20
+            2 => {
+                let fault = |kind| IntcodeError { tape_index: 2, opcode: Some(8), kind };
+                let a: i64 = state.read(21);
+                let b: i64 = 8;
+                let p = 20;
+                state.tape_index = 6;
+                if store(&mut state, p, (a == b) as i64) {
+                    return resume(state, output)
+                }
+            },
+            // 6: jt  20, #22
+            6 => {
+                let fault = |kind| IntcodeError { tape_index: 6, opcode: Some(5), kind };
+                let a: i64 = state.read(20);
+                let b: i64 = 22;
+                state.tape_index = 9;
+                if a != 0 {
+                    state.tape_index = b
+                }
+            },
+            // 9: lt  #8, 21, 20
+            9 => {
+                let fault = |kind| IntcodeError { tape_index: 9, opcode: Some(7), kind };
+                let a: i64 = 8;
+                let b: i64 = state.read(21);
+                let p = 20;
+                state.tape_index = 13;
+                if store(&mut state, p, (a < b) as i64) {
+                    return resume(state, output)
+                }
+            },
+            // 13: jf  20, #31
+            13 => {
+                let fault = |kind| IntcodeError { tape_index: 13, opcode: Some(6), kind };
+                let a: i64 = state.read(20);
+                let b: i64 = 31;
+                state.tape_index = 16;
+                if a == 0 {
+                    state.tape_index = b
+                }
+            },
+            // 16: jf  #0, #36
+            16 => {
+                let fault = |kind| IntcodeError { tape_index: 16, opcode: Some(6), kind };
+                let a: i64 = 0;
+                let b: i64 = 36;
+                state.tape_index = 19;
+                if a == 0 {
+                    state.tape_index = b
+                }
+            },
+            // 22: mul 21, #125, 20
+            22 => {
+                let fault = |kind| IntcodeError { tape_index: 22, opcode: Some(2), kind };
+                let a: i64 = state.read(21);
+                let b: i64 = 125;
+                let p = 20;
+                let value = a.checked_mul(b).ok_or(fault(ErrorKind::Overflow))?;
+                state.tape_index = 26;
+                if store(&mut state, p, value) {
+                    return resume(state, output)
+                }
+            },
+            // 26: out 20
+            26 => {
+                let fault = |kind| IntcodeError { tape_index: 26, opcode: Some(4), kind };
+                let a: i64 = state.read(20);
+                state.tape_index = 28;
+                output.push(a);
+            },
+            // 28: jt  #1, #46
+            28 => {
+                let fault = |kind| IntcodeError { tape_index: 28, opcode: Some(5), kind };
+                let a: i64 = 1;
+                let b: i64 = 46;
+                state.tape_index = 31;
+                if a != 0 {
+                    state.tape_index = b
+                }
+            },
+            // 31: out #999
+            31 => {
+                let fault = |kind| IntcodeError { tape_index: 31, opcode: Some(4), kind };
+                let a: i64 = 999;
+                state.tape_index = 33;
+                output.push(a);
+            },
+            // 33: jt  #1, #46
+            33 => {
+                let fault = |kind| IntcodeError { tape_index: 33, opcode: Some(5), kind };
+                let a: i64 = 1;
+                let b: i64 = 46;
+                state.tape_index = 36;
+                if a != 0 {
+                    state.tape_index = b
+                }
+            },
+            // 36: add #1000, #1, 20
+            36 => {
+                let fault = |kind| IntcodeError { tape_index: 36, opcode: Some(1), kind };
+                let a: i64 = 1000;
+                let b: i64 = 1;
+                let p = 20;
+                let value = a.checked_add(b).ok_or(fault(ErrorKind::Overflow))?;
+                state.tape_index = 40;
+                if store(&mut state, p, value) {
+                    return resume(state, output)
+                }
+            },
+            // 40: out 20
+            40 => {
+                let fault = |kind| IntcodeError { tape_index: 40, opcode: Some(4), kind };
+                let a: i64 = state.read(20);
+                state.tape_index = 42;
+                output.push(a);
+            },
+            // 42: jt  #1, #46
+            42 => {
+                let fault = |kind| IntcodeError { tape_index: 42, opcode: Some(5), kind };
+                let a: i64 = 1;
+                let b: i64 = 46;
+                state.tape_index = 45;
+                if a != 0 {
+                    state.tape_index = b
+                }
+            },
+            // 46: hlt
+            46 => return Ok(output),
+            _ => return resume(state, output)
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Generated by intcode's `transpile`, do not edit.
+use intcode::{ErrorKind, IntcodeError, ProgramState};
+
+pub const TAPE: [i64; 8] = [
+    1002, 7, 2, 7, 4, 7, 99, 4611686018427387904,
+];
+
+pub const CODE: [bool; 8] = [
+    true, true, true, true, true, true, true, false,
+];
+
+#[allow(dead_code)]
+fn address (pointer: i64) -> Result<usize, ErrorKind> {
+    if pointer >= 0 {
+        Ok(pointer as usize)
+    } else {
+        Err(ErrorKind::NegativePointer(pointer))
+    }
+}
+
+#[allow(dead_code)]
+fn relative (state: &ProgramState, offset: i64) -> Result<usize, ErrorKind> {
+    address(offset.checked_add(state.relative_base).ok_or(ErrorKind::Overflow)?)
+}
+
+#[allow(dead_code)]
+fn store (state: &mut ProgramState, address: usize, value: i64) -> bool {
+    let modified = address < CODE.len() && CODE[address] && state.tape[address] != value;
+    state.write(address, value);
+    modified
+}
+
+fn resume (mut state: ProgramState, mut output: Vec<i64>) -> Result<Vec<i64>, IntcodeError> {
+    while let Some(value) = intcode::step(&mut state)? {
+        output.push(value)
+    }
+    Ok(output)
+}
+
+#[allow(unused_mut, unused_variables, unreachable_code, clippy::all)]
+pub fn run (input: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+    let mut state = ProgramState::new(&TAPE);
+    state.input = input.to_vec();
+    let mut output = Vec::new();
+
+    loop {
+        match state.tape_index {
+            // 0: mul 7, #2, 7
+            0 => {
+                let fault = |kind| IntcodeError { tape_index: 0, opcode: Some(2), kind };
+                let a: i64 = state.read(7);
+                let b: i64 = 2;
+                let p = 7;
+                let value = a.checked_mul(b).ok_or(fault(ErrorKind::Overflow))?;
+                state.tape_index = 4;
+                if store(&mut state, p, value) {
+                    return resume(state, output)
+                }
+            },
+            // 4: out 7
+            4 => {
+                let fault = |kind| IntcodeError { tape_index: 4, opcode: Some(4), kind };
+                let a: i64 = state.read(7);
+                state.tape_index = 6;
+                output.push(a);
+            },
+            // 6: hlt
+            6 => return Ok(output),
+            _ => return resume(state, output)
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Generated by intcode's `transpile`, do not edit.
+use intcode::{ErrorKind, IntcodeError, ProgramState};
+
+pub const TAPE: [i64; 19] = [
+    104, 18, 1005, 17, 16, 1101, 4, 0, 0, 1101, 1, 0, 17, 1105, 1, 0,
+    99, 0, 42,
+];
+
+pub const CODE: [bool; 19] = [
+    true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+    true, false, false,
+];
+
+#[allow(dead_code)]
+fn address (pointer: i64) -> Result<usize, ErrorKind> {
+    if pointer >= 0 {
+        Ok(pointer as usize)
+    } else {
+        Err(ErrorKind::NegativePointer(pointer))
+    }
+}
+
+#[allow(dead_code)]
+fn relative (state: &ProgramState, offset: i64) -> Result<usize, ErrorKind> {
+    address(offset.checked_add(state.relative_base).ok_or(ErrorKind::Overflow)?)
+}
+
+#[allow(dead_code)]
+fn store (state: &mut ProgramState, address: usize, value: i64) -> bool {
+    let modified = address < CODE.len() && CODE[address] && state.tape[address] != value;
+    state.write(address, value);
+    modified
+}
+
+fn resume (mut state: ProgramState, mut output: Vec<i64>) -> Result<Vec<i64>, IntcodeError> {
+    while let Some(value) = intcode::step(&mut state)? {
+        output.push(value)
+    }
+    Ok(output)
+}
+
+#[allow(unused_mut, unused_variables, unreachable_code, clippy::all)]
+pub fn run (input: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+    let mut state = ProgramState::new(&TAPE);
+    state.input = input.to_vec();
+    let mut output = Vec::new();
+
+    loop {
+        match state.tape_index {
+            // 0: out #18
+            0 => {
+                let fault = |kind| IntcodeError { tape_index: 0, opcode: Some(4), kind };
+                let a: i64 = 18;
+                state.tape_index = 2;
+                output.push(a);
+            },
+            // 2: jt  17, #16
+            2 => {
+                let fault = |kind| IntcodeError { tape_index: 2, opcode: Some(5), kind };
+                let a: i64 = state.read(17);
+                let b: i64 = 16;
+                state.tape_index = 5;
+                if a != 0 {
+                    state.tape_index = b
+                }
+            },
+            // 5: add #4, #0, 0
+            5 => {
+                let fault = |kind| IntcodeError { tape_index: 5, opcode: Some(1), kind };
+                let a: i64 = 4;
+                let b: i64 = 0;
+                let p = 0;
+                let value = a.checked_add(b).ok_or(fault(ErrorKind::Overflow))?;
+                state.tape_index = 9;
+                if store(&mut state, p, value) {
+                    return resume(state, output)
+                }
+            },
+            // 9: add #1, #0, 17
+            9 => {
+                let fault = |kind| IntcodeError { tape_index: 9, opcode: Some(1), kind };
+                let a: i64 = 1;
+                let b: i64 = 0;
+                let p = 17;
+                let value = a.checked_add(b).ok_or(fault(ErrorKind::Overflow))?;
+                state.tape_index = 13;
+                if store(&mut state, p, value) {
+                    return resume(state, output)
+                }
+            },
+            // 13: jt  #1, #0
+            13 => {
+                let fault = |kind| IntcodeError { tape_index: 13, opcode: Some(5), kind };
+                let a: i64 = 1;
+                let b: i64 = 0;
+                state.tape_index = 16;
+                if a != 0 {
+                    state.tape_index = b
+                }
+            },
+            // 16: hlt
+            16 => return Ok(output),
+            _ => return resume(state, output)
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Generated by intcode's `transpile`, do not edit.
+use intcode::{ErrorKind, IntcodeError, ProgramState};
+
+pub const TAPE: [i64; 16] = [
+    109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+];
+
+pub const CODE: [bool; 16] = [
+    true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+];
+
+#[allow(dead_code)]
+fn address (pointer: i64) -> Result<usize, ErrorKind> {
+    if pointer >= 0 {
+        Ok(pointer as usize)
+    } else {
+        Err(ErrorKind::NegativePointer(pointer))
+    }
+}
+
+#[allow(dead_code)]
+fn relative (state: &ProgramState, offset: i64) -> Result<usize, ErrorKind> {
+    address(offset.checked_add(state.relative_base).ok_or(ErrorKind::Overflow)?)
+}
+
+#[allow(dead_code)]
+fn store (state: &mut ProgramState, address: usize, value: i64) -> bool {
+    let modified = address < CODE.len() && CODE[address] && state.tape[address] != value;
+    state.write(address, value);
+    modified
+}
+
+fn resume (mut state: ProgramState, mut output: Vec<i64>) -> Result<Vec<i64>, IntcodeError> {
+    while let Some(value) = intcode::step(&mut state)? {
+        output.push(value)
+    }
+    Ok(output)
+}
+
+#[allow(unused_mut, unused_variables, unreachable_code, clippy::all)]
+pub fn run (input: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+    let mut state = ProgramState::new(&TAPE);
+    state.input = input.to_vec();
+    let mut output = Vec::new();
+
+    loop {
+        match state.tape_index {
+            // 0: arb #1
+            0 => {
+                let fault = |kind| IntcodeError { tape_index: 0, opcode: Some(9), kind };
+                let a: i64 = 1;
+                let value = state.relative_base.checked_add(a).ok_or(fault(ErrorKind::Overflow))?;
+                state.tape_index = 2;
+                state.relative_base = value;
+            },
+            // 2: out rb-1
+            2 => {
+                let fault = |kind| IntcodeError { tape_index: 2, opcode: Some(4), kind };
+                let a: i64 = state.read(relative(&state, -1).map_err(fault)?);
+                state.tape_index = 4;
+                output.push(a);
+            },
+            // 4: add 100, #1, 100
+            4 => {
+                let fault = |kind| IntcodeError { tape_index: 4, opcode: Some(1), kind };
+                let a: i64 = state.read(100);
+                let b: i64 = 1;
+                let p = 100;
+                let value = a.checked_add(b).ok_or(fault(ErrorKind::Overflow))?;
+                state.tape_index = 8;
+                if store(&mut state, p, value) {
+                    return resume(state, output)
+                }
+            },
+            // 8: eq  100, #16, 101
+            8 => {
+                let fault = |kind| IntcodeError { tape_index: 8, opcode: Some(8), kind };
+                let a: i64 = state.read(100);
+                let b: i64 = 16;
+                let p = 101;
+                state.tape_index = 12;
+                if store(&mut state, p, (a == b) as i64) {
+                    return resume(state, output)
+                }
+            },
+            // 12: jf  101, #0
+            12 => {
+                let fault = |kind| IntcodeError { tape_index: 12, opcode: Some(6), kind };
+                let a: i64 = state.read(101);
+                let b: i64 = 0;
+                state.tape_index = 15;
+                if a == 0 {
+                    state.tape_index = b
+                }
+            },
+            // 15: hlt
+            15 => return Ok(output),
+            _ => return resume(state, output)
+        }
+    }
+}
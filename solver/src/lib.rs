@@ -0,0 +1,23 @@
+use std::fmt;
+
+// One day of the puzzle. The input is parsed once and shared by both parts;
+// it may borrow from the input text (day 14 does).
+pub trait Solver {
+    type Input<'a>;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    fn parse (input: &str) -> Self::Input<'_>;
+    fn part1 (input: &Self::Input<'_>) -> Self::Answer1;
+    fn part2 (input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+// The second answer of a day whose part 2 isn't solved.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not solved")
+    }
+}